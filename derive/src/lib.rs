@@ -0,0 +1,33 @@
+//! Derive macros for [`bijective-enum-map`], which should be used through its `derive` feature
+//! rather than by depending on this crate directly.
+//!
+//! [`bijective-enum-map`]: https://docs.rs/bijective-enum-map
+
+mod named;
+mod rename;
+mod subset;
+
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+
+// Documented at its re-export in `bijective-enum-map`.
+#[proc_macro_derive(NamedEnumMap, attributes(enum_map))]
+pub fn derive_named_enum_map(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    named::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+// Documented at its re-export in `bijective-enum-map`.
+#[proc_macro_derive(SubsetEnumMap, attributes(enum_map))]
+pub fn derive_subset_enum_map(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    subset::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
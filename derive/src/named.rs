@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Ident, LitStr, Path};
+
+use crate::rename::RenameRule;
+
+
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`NamedEnumMap` can only be derived for enums",
+            ));
+        }
+    };
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "`NamedEnumMap` cannot be derived for generic enums",
+        ));
+    }
+
+    let mut rename_all = RenameRule::None;
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("enum_map")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename_all") {
+                rename_all = RenameRule::from_lit(&meta.value()?.parse()?)?;
+                Ok(())
+            } else if meta.path.is_ident("superset") {
+                // Used by `SubsetEnumMap`.
+                meta.value()?.parse::<Path>()?;
+                Ok(())
+            } else {
+                Err(meta.error("unknown `enum_map` attribute; expected `rename_all`"))
+            }
+        })?;
+    }
+
+    let mut variants = Vec::with_capacity(data.variants.len());
+    let mut names = Vec::with_capacity(data.variants.len());
+    let mut seen: HashMap<String, &Ident> = HashMap::new();
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "`NamedEnumMap` requires every variant to be a unit variant",
+            ));
+        }
+
+        let mut rename = None;
+        for attr in variant.attrs.iter().filter(|attr| attr.path().is_ident("enum_map")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    let value = meta.value()?;
+                    if value.peek(LitStr) {
+                        rename = Some(value.parse::<LitStr>()?.value());
+                    } else {
+                        // An identifier renames the variant for `SubsetEnumMap`.
+                        value.parse::<Ident>()?;
+                    }
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown `enum_map` variant attribute; expected `rename`"))
+                }
+            })?;
+        }
+
+        let name = rename.unwrap_or_else(|| rename_all.apply(&variant.ident.to_string()));
+
+        if let Some(other) = seen.insert(name.clone(), &variant.ident) {
+            return Err(syn::Error::new_spanned(
+                &variant.ident,
+                format!("`{}` has the same name as `{other}`: \"{name}\"", variant.ident),
+            ));
+        }
+
+        variants.push(&variant.ident);
+        names.push(name);
+    }
+
+    let enum_ident = &input.ident;
+
+    Ok(quote! {
+        ::bijective_enum_map::injective_enum_map! {
+            #enum_ident, &'static str, &str,
+            #( #variants <=> #names, )*
+        }
+    })
+}
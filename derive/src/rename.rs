@@ -0,0 +1,130 @@
+use syn::LitStr;
+
+
+/// A case conversion applied to the names of variants, with the same names and behavior as
+/// serde's `rename_all`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameRule {
+    /// Leave variant names as they are written, which is usually `PascalCase`.
+    None,
+    LowerCase,
+    UpperCase,
+    PascalCase,
+    CamelCase,
+    SnakeCase,
+    ScreamingSnakeCase,
+    KebabCase,
+    ScreamingKebabCase,
+}
+
+impl RenameRule {
+    const RULES: [(&'static str, Self); 8] = [
+        ("lowercase",            Self::LowerCase),
+        ("UPPERCASE",            Self::UpperCase),
+        ("PascalCase",           Self::PascalCase),
+        ("camelCase",            Self::CamelCase),
+        ("snake_case",           Self::SnakeCase),
+        ("SCREAMING_SNAKE_CASE", Self::ScreamingSnakeCase),
+        ("kebab-case",           Self::KebabCase),
+        ("SCREAMING-KEBAB-CASE", Self::ScreamingKebabCase),
+    ];
+
+    pub fn from_lit(lit: &LitStr) -> syn::Result<Self> {
+        let rule = lit.value();
+
+        Self::RULES
+            .iter()
+            .find(|(name, _)| *name == rule)
+            .map(|(_, rule)| *rule)
+            .ok_or_else(|| {
+                let expected = Self::RULES
+                    .iter()
+                    .map(|(name, _)| format!("\"{name}\""))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                syn::Error::new(
+                    lit.span(),
+                    format!("unknown rename rule \"{rule}\"; expected one of {expected}"),
+                )
+            })
+    }
+
+    /// Apply the rule to a `PascalCase` variant name.
+    #[must_use]
+    pub fn apply(self, variant: &str) -> String {
+        match self {
+            Self::None | Self::PascalCase => variant.to_owned(),
+            Self::LowerCase               => variant.to_ascii_lowercase(),
+            Self::UpperCase               => variant.to_ascii_uppercase(),
+            Self::CamelCase               => {
+                let mut chars = variant.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None        => String::new(),
+                }
+            }
+            Self::SnakeCase               => separate_words(variant, '_'),
+            Self::ScreamingSnakeCase      => separate_words(variant, '_').to_ascii_uppercase(),
+            Self::KebabCase               => separate_words(variant, '-'),
+            Self::ScreamingKebabCase      => separate_words(variant, '-').to_ascii_uppercase(),
+        }
+    }
+}
+
+/// Lowercase a `PascalCase` name, inserting `separator` before each uppercase letter other than
+/// the first character.
+fn separate_words(variant: &str, separator: char) -> String {
+    let mut output = String::with_capacity(variant.len() + variant.len() / 2);
+
+    for (i, ch) in variant.char_indices() {
+        if i > 0 && ch.is_uppercase() {
+            output.push(separator);
+        }
+        output.push(ch.to_ascii_lowercase());
+    }
+
+    output
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::RenameRule;
+
+    #[test]
+    fn rules() {
+        let cases = [
+            (RenameRule::None,               "HttpStatus"),
+            (RenameRule::LowerCase,          "httpstatus"),
+            (RenameRule::UpperCase,          "HTTPSTATUS"),
+            (RenameRule::PascalCase,         "HttpStatus"),
+            (RenameRule::CamelCase,          "httpStatus"),
+            (RenameRule::SnakeCase,          "http_status"),
+            (RenameRule::ScreamingSnakeCase, "HTTP_STATUS"),
+            (RenameRule::KebabCase,          "http-status"),
+            (RenameRule::ScreamingKebabCase, "HTTP-STATUS"),
+        ];
+
+        for (rule, expected) in cases {
+            assert_eq!(rule.apply("HttpStatus"), expected, "{rule:?}");
+        }
+    }
+
+    #[test]
+    fn single_letters() {
+        assert_eq!(RenameRule::KebabCase.apply("A"), "a");
+        assert_eq!(RenameRule::SnakeCase.apply("V1"), "v1");
+        assert_eq!(RenameRule::SnakeCase.apply("ABC"), "a_b_c");
+        assert_eq!(RenameRule::CamelCase.apply(""), "");
+    }
+
+    #[test]
+    fn from_lit() {
+        let lit = syn::LitStr::new("kebab-case", proc_macro2::Span::call_site());
+        assert_eq!(RenameRule::from_lit(&lit).unwrap(), RenameRule::KebabCase);
+
+        let lit = syn::LitStr::new("Kebab-Case", proc_macro2::Span::call_site());
+        assert!(RenameRule::from_lit(&lit).is_err());
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, Ident, LitStr, Path};
+
+
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`SubsetEnumMap` can only be derived for enums",
+            ));
+        }
+    };
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "`SubsetEnumMap` cannot be derived for generic enums",
+        ));
+    }
+
+    let mut superset = None;
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("enum_map")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("superset") {
+                if superset.is_some() {
+                    return Err(meta.error("duplicate `superset` attribute"));
+                }
+                superset = Some(meta.value()?.parse::<Path>()?);
+                Ok(())
+            } else if meta.path.is_ident("rename_all") {
+                // Used by `NamedEnumMap`.
+                meta.value()?.parse::<LitStr>()?;
+                Ok(())
+            } else {
+                Err(meta.error("unknown `enum_map` attribute; expected `superset`"))
+            }
+        })?;
+    }
+
+    let superset = match superset {
+        Some(superset) => superset,
+        None => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`SubsetEnumMap` requires an `#[enum_map(superset = Enum)]` attribute naming the \
+                 superset enum",
+            ));
+        }
+    };
+
+    let mut arms = Vec::with_capacity(data.variants.len());
+    let mut seen: HashMap<Ident, &Ident> = HashMap::new();
+
+    for variant in &data.variants {
+        let mut target = None;
+        for attr in variant.attrs.iter().filter(|attr| attr.path().is_ident("enum_map")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    let value = meta.value()?;
+                    if value.peek(LitStr) {
+                        // A string renames the variant for `NamedEnumMap`.
+                        value.parse::<LitStr>()?;
+                    } else if target.is_some() {
+                        return Err(meta.error("duplicate `rename` attribute"));
+                    } else {
+                        target = Some(value.parse::<Ident>()?);
+                    }
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown `enum_map` variant attribute; expected `rename`"))
+                }
+            })?;
+        }
+
+        let ident = &variant.ident;
+        let target = target.unwrap_or_else(|| ident.clone());
+
+        if let Some(other) = seen.insert(target.clone(), ident) {
+            return Err(syn::Error::new_spanned(
+                ident,
+                format!("`{ident}` maps to the same variant as `{other}`: `{target}`"),
+            ));
+        }
+
+        // The fields are bound to the same names on either side of the arm, so that the arm
+        // also works as a pattern in the reverse direction.
+        let arm = match &variant.fields {
+            Fields::Unit => quote! { #ident <=> #superset::#target },
+            Fields::Unnamed(fields) => {
+                let bindings = (0..fields.unnamed.len())
+                    .map(|index| format_ident!("__field{}", index))
+                    .collect::<Vec<_>>();
+                quote! { #ident(#(#bindings),*) <=> #superset::#target(#(#bindings),*) }
+            }
+            Fields::Named(fields) => {
+                let names = fields.named.iter().map(|field| &field.ident).collect::<Vec<_>>();
+                quote! { #ident { #(#names),* } <=> #superset::#target { #(#names),* } }
+            }
+        };
+
+        arms.push(arm);
+    }
+
+    let enum_ident = &input.ident;
+
+    Ok(quote! {
+        ::bijective_enum_map::injective_enum_map! {
+            #enum_ident, #superset,
+            #( #arms, )*
+        }
+    })
+}
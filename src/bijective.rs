@@ -13,6 +13,11 @@
 /// Unintended options like mapping into `Range<u8>` and from `u8` might be possible, but are
 /// not tested here. Note that non-unit variants are supported.
 ///
+/// An arm may instead be written as `Variant <=> value => pattern`, in which case `value` is the
+/// canonical value that the variant maps into, and the reverse conversion matches `pattern` (for
+/// instance, a range of values) rather than `value`. The value must still be a valid pattern.
+/// Such arms are not supported by `mode = tables` or `json_schema = true`.
+///
 /// This map is intended to be "bijective", which means that it is both "surjective" and
 /// "injective". Being surjective means that every value of the target type to map the enum into
 /// should be associated with some enum variant. This is enforced by a `match` mapping values into
@@ -29,6 +34,80 @@
 /// case, only the first duplicate arm (in each direction) will be taken for the duplicated variant
 /// or value.
 ///
+/// The generated implementations are documented with a list of the arms of the map in their
+/// direction, so that the mapping is visible in rustdoc without reading the source.
+///
+/// # Options
+///
+/// Options may be given after the types and before the arms, in the form `option = value`.
+///
+/// - `mode = match` (the default): implement the conversions with `match` statements.
+/// - `mode = tables`: store the values of the map in `const` tables, one indexed by the enum
+///   for the `From<$enum_ty>` conversion and one scanned by the `From<$from>` conversion. This can
+///   result in smaller code for large maps (e.g., on embedded targets). Every variant must be a
+///   unit variant, every value must be a constant expression, `$into` must be `Copy`, and `$from`
+///   must be `PartialEq`. The values are still checked to be exhaustive patterns at compile time,
+///   as in `mode = match`.
+/// - `mode = contiguous`: for a fieldless enum which maps each variant to its own discriminant,
+///   implement the `From<$enum_ty>` conversion with an `as` cast, and the `From<$from>`
+///   conversion with an index into a `const` table of the variants. The arms must be listed in
+///   order of consecutive values; this, and that each value equals the discriminant of its
+///   variant, is checked at compile time, which requires Rust 1.57. The discriminants and values
+///   are compared as `i128`, so a discriminant which does not fit in `$into` is rejected rather
+///   than truncated by the cast; declaring a `#[repr]` matching `$into` keeps the two in
+///   agreement. Every variant must be a unit variant, the enum must be `Copy`, and `$into` and
+///   `$from` must be primitive integer types. In practice, this is only possible for an enum with
+///   a variant for every value of `u8` or `i8`.
+/// - `inline = default` (the default), `inline = never`, or `inline = always`: emit `#[inline]`,
+///   `#[inline(never)]`, or `#[inline(always)]` (respectively) on the generated conversion
+///   functions and on the inherent functions generated by other options. For instance, `never`
+///   can avoid code bloat from a large map which is used in many places, while `always` may help
+///   a small map used in a hot loop.
+/// - `vis = pub` (the default): the visibility of any inherent items (associated functions or
+///   constants) generated for the enum, such as `pub(crate)` or `pub(self)` (which is private).
+///   Trait implementations are not affected.
+/// - `iter = true`: generate an inherent `iter()` function on the enum, returning an iterator
+///   over every variant in the order of the arms (backed by an array). Every variant must be a
+///   unit variant, and only one map of the enum may enable this option. Defaults to `false`.
+/// - `ordinal = true`: generate inherent `next()`, `prev()`, `wrapping_next()`, and
+///   `checked_add(n)` functions on the enum, which navigate between variants by their values.
+///   Every variant must be a unit variant mapped to an integer constant, and the values must be
+///   exactly `0..N` (in any order), where `N` is the number of arms; this is checked at compile
+///   time, and requires Rust 1.57. The conversions must be into and from the same integer type,
+///   and only one map of the enum may enable this option. Defaults to `false`.
+/// - `expected_values = true`: generate an inherent `expected_values()` function on the enum,
+///   returning a `&'static [$into]` slice of the values of the map in the order of the arms
+///   (for instance, to list the accepted inputs in an error message). Every value must be a
+///   constant expression; for an arm written as `Variant <=> value => pattern`, only `value`
+///   is listed, even though the reverse conversion accepts every value matching `pattern`. Only
+///   one map of the enum may enable this option. Defaults to `false`.
+/// - `bytes = le` or `bytes = be`: generate inherent `try_read_from(bytes)` and `write_to(buf)`
+///   functions on the enum, which read a `$from` from the start of a byte slice and convert it
+///   into the enum, or write the `$into` value of the enum to the start of a buffer, in
+///   little-endian or big-endian byte order. Both return the rest of the slice, and fail with
+///   [`ReadError`] or [`BufferTooShort`]. `$from` and `$into` must be primitive integer types,
+///   and the enum must implement `Clone`, as `write_to` takes `&self` and converts a clone.
+///   Only one map of the enum may enable this option. Defaults to `false`.
+/// - `proptest = true`: generate an inherent `any_variant()` function on the enum, returning a
+///   `proptest` strategy which generates every variant in the map, with the fields of non-unit
+///   variants generated by their `Arbitrary` implementations (so the fields of each arm must be
+///   bound to identifiers, as in `Variant(a, b)` or `Variant { a, b }`). If every variant is a
+///   unit variant, `Arbitrary` is also implemented for the enum. Strategies for the fields of
+///   some variants can be given with `proptest = { Variant(strategy, ...), ... }`, with one
+///   strategy per field in the order the arm binds them (for instance, `any::<T>()` to keep a
+///   field arbitrary); the other variants use `Arbitrary`, as with `proptest = true`. The enum
+///   must implement `Debug`, and only one map of the enum may enable this option. Requires the
+///   `proptest` feature. Defaults to `false`.
+/// - `json_schema = true`: implement `schemars::JsonSchema` for the enum, with a schema listing
+///   the values of the map as the permitted values, and with the JSON type of `$into`. The values
+///   must implement `serde::Serialize`, and `$into` must implement `schemars::JsonSchema`. Arms
+///   written as `Variant <=> value => pattern` are rejected, since the schema could not list the
+///   values matched by `pattern`. This requires the `schemars` feature. Defaults to `false`.
+/// - `const_assert = true`: check at compile time that the values are distinct and that there is
+///   one arm per value of `$into`, exactly as [`assert_bijective`] does. Every variant must be a
+///   unit variant, and every value must be a constant of a supported type; this requires
+///   Rust 1.57. Defaults to `false`.
+///
 /// # Examples
 ///
 /// ## Map into and from two other types:
@@ -156,38 +235,38 @@
 ///     Empty::from(_new_another_empty())
 /// }
 /// ```
+///
+/// [`assert_bijective`]: crate::assert_bijective
+/// [`ReadError`]: crate::ReadError
+/// [`BufferTooShort`]: crate::BufferTooShort
 #[macro_export]
 macro_rules! bijective_enum_map {
     { $enum_ty:ty, $into:ty, $from:ty, $($body:tt)* } => {
-        $crate::__impl_from_enum! { $enum_ty, $into, $($body)* }
-        $crate::__impl_enum_from! { $enum_ty, $from, $($body)* }
+        $crate::__enum_map_options! { @start bijective [$enum_ty, $into, $from] $($body)* }
     };
 
     { $enum_ty:ty, $into:ty, $from:ty } => {
-        $crate::__impl_from_enum! { $enum_ty, $into }
-        $crate::__impl_enum_from! { $enum_ty, $from }
+        $crate::__enum_map_options! { @start bijective [$enum_ty, $into, $from] }
     };
 
     { $enum_ty:ty, $both:ty, $($body:tt)* } => {
-        $crate::__impl_from_enum! { $enum_ty, $both, $($body)* }
-        $crate::__impl_enum_from! { $enum_ty, $both, $($body)* }
+        $crate::__enum_map_options! { @start bijective [$enum_ty, $both] $($body)* }
     };
 
     { $enum_ty:ty, $both:ty } => {
-        $crate::__impl_from_enum! { $enum_ty, $both }
-        $crate::__impl_enum_from! { $enum_ty, $both }
+        $crate::__enum_map_options! { @start bijective [$enum_ty, $both] }
     };
 }
 
 
 #[cfg(test)]
 mod tests {
-    use crate::bijective_enum_map;
-
     #[test]
     fn empty_both_specified() {
         #[derive(Debug, PartialEq, Eq)]
+        #[allow(dead_code)]
         enum Empty {}
+        #[allow(dead_code)]
         enum AnotherEmpty {}
 
         // The trailing comma is always optional
@@ -208,7 +287,9 @@ mod tests {
     #[test]
     fn empty_one_specified() {
         #[derive(Debug, PartialEq, Eq)]
+        #[allow(dead_code)]
         enum Empty {}
+        #[allow(dead_code)]
         enum AnotherEmpty {}
 
         // The trailing comma is always optional
@@ -281,9 +362,13 @@ mod tests {
 
     #[test]
     fn trailing_commas() {
+        #[allow(dead_code)]
         enum Empty {}
+        #[allow(dead_code)]
         enum AnotherEmpty {}
+        #[allow(dead_code)]
         enum YetAnotherEmpty {}
+        #[allow(dead_code)]
         enum AFourthEmpty {}
 
         enum Trivial {
@@ -367,6 +452,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn pattern_arms() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Half {
+            Low,
+            High,
+        }
+
+        bijective_enum_map! {
+            Half, u8,
+            Low  <=> 0   => 0..=127,
+            High <=> 255 => 128..,
+        }
+
+        assert_eq!(u8::from(Half::Low), 0);
+        assert_eq!(u8::from(Half::High), 255);
+        assert_eq!(Half::from(100_u8), Half::Low);
+        assert_eq!(Half::from(128_u8), Half::High);
+    }
+
+    #[test]
+    fn tables_mode() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Enum {
+            One,
+            Two,
+            Three,
+        }
+
+        #[derive(Debug, PartialEq, Eq)]
+        enum Switch {
+            On,
+            Off,
+        }
+
+        bijective_enum_map! {
+            Switch, bool,
+            mode = tables,
+            On  <=> true,
+            Off <=> false,
+        }
+        bijective_enum_map! {
+            Enum, Option<bool>,
+            mode = tables,
+            One   <=> None,
+            Two   <=> Some(false),
+            Three <=> Some(true),
+        }
+
+        assert!(!bool::from(Switch::Off));
+        assert_eq!(Switch::from(true), Switch::On);
+        assert_eq!(Option::from(Enum::Two), Some(false));
+        assert_eq!(Enum::from(None), Enum::One);
+        assert_eq!(Enum::from(Some(true)), Enum::Three);
+    }
+
     #[test]
     fn intentionally_non_surjective() {
         #[derive(Debug, PartialEq, Eq)]
@@ -530,6 +671,22 @@ pub mod compile_fail_tests {
     /// ```
     pub fn _nonempty_to_enum_not_injective_warning() {}
 
+    /// ```compile_fail,E0004
+    /// use bijective_enum_map::bijective_enum_map;
+    /// enum Nonempty {
+    ///     Something,
+    ///     SomethingElse,
+    /// }
+    ///
+    /// bijective_enum_map! {
+    ///     Nonempty, u8,
+    ///     mode = tables,
+    ///     Something     <=> 0,
+    ///     SomethingElse <=> 1,
+    /// }
+    /// ```
+    pub fn _tables_not_surjective() {}
+
     // Doesn't seem to have a compiler error number
     /// ```compile_fail
     /// use bijective_enum_map::bijective_enum_map;
@@ -544,6 +701,38 @@ pub mod compile_fail_tests {
     /// ```
     pub fn _missing_comma() {}
 
+    /// ```compile_fail
+    /// use bijective_enum_map::bijective_enum_map;
+    /// enum Switch {
+    ///     On,
+    ///     Off,
+    /// }
+    ///
+    /// bijective_enum_map! {
+    ///     Switch, std::borrow::Cow<'static, str>,
+    ///     mode = cow,
+    ///     On  <=> "on",
+    ///     Off <=> "off",
+    /// }
+    /// ```
+    pub fn _cow_mode() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::bijective_enum_map;
+    /// enum Switch {
+    ///     On,
+    ///     Off,
+    /// }
+    ///
+    /// bijective_enum_map! {
+    ///     Switch, bool,
+    ///     error: () = (),
+    ///     On  <=> true,
+    ///     Off <=> false,
+    /// }
+    /// ```
+    pub fn _error_option() {}
+
     /// ```
     /// use bijective_enum_map::bijective_enum_map;
     /// enum Nonempty {
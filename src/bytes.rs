@@ -0,0 +1,206 @@
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_bytes {
+    { false $($rest:tt)* } => {};
+
+    { le [$vis:vis] [$inline:meta] $enum_ty:ty, $into:ty, $try_from:ty } => {
+        $crate::__impl_bytes! {
+            @impl [$vis] [$inline] $enum_ty, $into, $try_from,
+            from_le_bytes, to_le_bytes, "little-endian"
+        }
+    };
+
+    { be [$vis:vis] [$inline:meta] $enum_ty:ty, $into:ty, $try_from:ty } => {
+        $crate::__impl_bytes! {
+            @impl [$vis] [$inline] $enum_ty, $into, $try_from,
+            from_be_bytes, to_be_bytes, "big-endian"
+        }
+    };
+
+    {
+        @impl [$vis:vis] [$inline:meta] $enum_ty:ty, $into:ty, $try_from:ty,
+        $from_bytes:ident, $to_bytes:ident, $endianness:literal
+    } => {
+        impl $enum_ty {
+            #[doc = ::core::concat!(
+                "Read a `", ::core::stringify!($try_from), "` from the start of `bytes` (in ",
+                $endianness, " byte order) and convert it into the enum, returning the enum ",
+                "and the remaining bytes.",
+            )]
+            #[doc = ""]
+            #[doc = "# Errors"]
+            #[doc = "Fails if `bytes` is too short, or if the value does not match any variant."]
+            #[$inline]
+            $vis fn try_read_from(
+                bytes: &[u8],
+            ) -> ::core::result::Result<
+                (Self, &[u8]),
+                $crate::ReadError<<Self as ::core::convert::TryFrom<$try_from>>::Error>,
+            > {
+                const SIZE: usize = ::core::mem::size_of::<$try_from>();
+
+                if bytes.len() < SIZE {
+                    return ::core::result::Result::Err($crate::ReadError::BufferTooShort(
+                        $crate::BufferTooShort,
+                    ));
+                }
+                let (value, rest) = bytes.split_at(SIZE);
+                let mut array = [0_u8; SIZE];
+                array.copy_from_slice(value);
+
+                let value = <$try_from>::$from_bytes(array);
+                match <Self as ::core::convert::TryFrom<$try_from>>::try_from(value) {
+                    ::core::result::Result::Ok(variant) => {
+                        ::core::result::Result::Ok((variant, rest))
+                    }
+                    ::core::result::Result::Err(error) => {
+                        ::core::result::Result::Err($crate::ReadError::NoMatchingVariant(error))
+                    }
+                }
+            }
+
+            #[doc = ::core::concat!(
+                "Convert the enum into a `", ::core::stringify!($into), "` and write it to the ",
+                "start of `buf` (in ", $endianness, " byte order), returning the rest of `buf`.",
+            )]
+            #[doc = ""]
+            #[doc = "The conversion takes the enum by value, so it is cloned from `self`."]
+            #[doc = ""]
+            #[doc = "# Errors"]
+            #[doc = "Fails, without writing anything, if `buf` is too short."]
+            #[$inline]
+            $vis fn write_to<'buf>(
+                &self,
+                buf: &'buf mut [u8],
+            ) -> ::core::result::Result<&'buf mut [u8], $crate::BufferTooShort> {
+                let variant = ::core::clone::Clone::clone(self);
+                let bytes = <$into as ::core::convert::From<Self>>::from(variant).$to_bytes();
+
+                if buf.len() < bytes.len() {
+                    return ::core::result::Result::Err($crate::BufferTooShort);
+                }
+                let (value, rest) = buf.split_at_mut(bytes.len());
+                value.copy_from_slice(&bytes);
+                ::core::result::Result::Ok(rest)
+            }
+        }
+    };
+
+    { $bytes:tt $($rest:tt)* } => {
+        ::core::compile_error!(::core::concat!(
+            "expected `le`, `be`, or `false` for `bytes`, found `",
+            ::core::stringify!($bytes), "`",
+        ));
+    };
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{injective_enum_map, BufferTooShort, NoMatchingVariant, ReadError};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Message {
+        Ping,
+        Pong,
+        Close,
+    }
+
+    injective_enum_map! {
+        Message, u16,
+        bytes = le,
+        error: NoMatchingVariant,
+        Ping  <=> 0x0001,
+        Pong  <=> 0x0002,
+        Close <=> 0x0100,
+    }
+
+    #[test]
+    fn read() {
+        assert_eq!(Message::try_read_from(&[0x02, 0x00]), Ok((Message::Pong, &[][..])));
+        assert_eq!(Message::try_read_from(&[0x00, 0x01, 0x07]), Ok((Message::Close, &[0x07][..])));
+        assert_eq!(
+            Message::try_read_from(&[0x01]),
+            Err(ReadError::BufferTooShort(BufferTooShort)),
+        );
+        assert_eq!(
+            Message::try_read_from(&[0x01, 0x01]),
+            Err(ReadError::NoMatchingVariant(NoMatchingVariant)),
+        );
+    }
+
+    #[test]
+    fn write() {
+        let mut buf = [0_u8; 5];
+
+        let rest = Message::Close.write_to(&mut buf).unwrap();
+        let rest = Message::Ping.write_to(rest).unwrap();
+        assert_eq!(rest.len(), 1);
+        assert_eq!(Message::Pong.write_to(rest), Err(BufferTooShort));
+        assert_eq!(buf, [0x00, 0x01, 0x01, 0x00, 0x00]);
+
+        let (first, rest) = Message::try_read_from(&buf).unwrap();
+        let (second, _) = Message::try_read_from(rest).unwrap();
+        assert_eq!([first, second], [Message::Close, Message::Ping]);
+    }
+
+    #[test]
+    fn big_endian() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Tag {
+            Small,
+            Large,
+        }
+
+        injective_enum_map! {
+            Tag, i32,
+            bytes = be,
+            vis = pub(crate),
+            Small <=> -2,
+            Large <=> 0x0102_0304,
+        }
+
+        let mut buf = [0_u8; 4];
+        assert_eq!(Tag::Large.write_to(&mut buf), Ok(&mut [][..]));
+        assert_eq!(buf, [0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(Tag::try_read_from(&[0xFF, 0xFF, 0xFF, 0xFE]), Ok((Tag::Small, &[][..])));
+        assert_eq!(Tag::try_read_from(&[0, 0, 0, 0]), Err(ReadError::NoMatchingVariant(())));
+    }
+}
+
+#[cfg(doctest)]
+pub mod compile_fail_tests {
+    /// ```compile_fail
+    /// use bijective_enum_map::injective_enum_map;
+    /// #[derive(Clone, Copy)]
+    /// enum Switch {
+    ///     On,
+    ///     Off,
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     Switch, &'static str, &str,
+    ///     bytes = le,
+    ///     On  <=> "on",
+    ///     Off <=> "off",
+    /// }
+    /// ```
+    pub fn _non_integer() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::injective_enum_map;
+    /// #[derive(Clone, Copy)]
+    /// enum Switch {
+    ///     On,
+    ///     Off,
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     Switch, u8,
+    ///     bytes = native,
+    ///     On  <=> 1,
+    ///     Off <=> 0,
+    /// }
+    /// ```
+    pub fn _unknown_endianness() {}
+}
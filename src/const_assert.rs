@@ -0,0 +1,751 @@
+/// Check at compile time that the values of a map are distinct, failing the build with an error
+/// naming each pair of variants with the same value.
+///
+/// This macro takes the same input as [`injective_enum_map`] (without options), but only
+/// performs the check; it does not implement any conversions. The `const_assert = true` option
+/// of [`injective_enum_map`] performs the same check on the map which it implements.
+///
+/// Every arm must map a unit variant to a constant value of a supported type: the primitive
+/// integer types, `bool`, `char`, or `&str` (with any lifetime). Each variant of the enum must
+/// have exactly one arm, as checked by a `match` over the enum. The values are compared at
+/// compile time, via const evaluation; the check therefore requires Rust 1.57 or later.
+///
+/// By comparison, the `#[warn(unreachable_patterns)]` warning which the map macros rely on
+/// is only a warning, and is not always emitted.
+///
+/// # Examples
+/// ```
+/// use bijective_enum_map::{assert_injective, injective_enum_map};
+/// #[derive(Debug, PartialEq, Eq)]
+/// enum Command {
+///     Reset,
+///     Read,
+///     Write,
+/// }
+///
+/// assert_injective! {
+///     Command, &'static str, &str,
+///     Reset <=> "reset",
+///     Read  <=> "read",
+///     Write <=> "write",
+/// }
+///
+/// // Equivalently:
+/// injective_enum_map! {
+///     Command, &'static str, &str,
+///     const_assert = true,
+///     Reset <=> "reset",
+///     Read  <=> "read",
+///     Write <=> "write",
+/// }
+/// ```
+///
+/// ```compile_fail,E0080
+/// use bijective_enum_map::assert_injective;
+/// enum Command {
+///     Reset,
+///     Read,
+///     Write,
+/// }
+///
+/// // error: `Reset` and `Write` map to the same value
+/// assert_injective! {
+///     Command, u8,
+///     Reset <=> 0,
+///     Read  <=> 1,
+///     Write <=> 0,
+/// }
+/// ```
+///
+/// [`injective_enum_map`]: crate::injective_enum_map
+#[macro_export]
+macro_rules! assert_injective {
+    { $enum_ty:ty, $into:ty, $try_from:ty, $($body:tt)* } => {
+        $crate::__const_assert! { true injective $enum_ty, $into, $($body)* }
+    };
+
+    { $enum_ty:ty, $into:ty, $try_from:ty } => {
+        $crate::__const_assert! { true injective $enum_ty, $into, }
+    };
+
+    { $enum_ty:ty, $both:ty, $($body:tt)* } => {
+        $crate::__const_assert! { true injective $enum_ty, $both, $($body)* }
+    };
+
+    { $enum_ty:ty, $both:ty } => {
+        $crate::__const_assert! { true injective $enum_ty, $both, }
+    };
+}
+
+/// Check at compile time that the values of a map are distinct and that there is one arm for
+/// every value of the target type, failing the build with a precise error otherwise.
+///
+/// This macro takes the same input as [`bijective_enum_map`] (without options), but only
+/// performs the check; it does not implement any conversions. The `const_assert = true` option
+/// of [`bijective_enum_map`] performs the same check on the map which it implements.
+///
+/// The requirements on the arms are the same as for [`assert_injective`], and repeated values
+/// are reported in the same way. Additionally, the number of arms must equal the number of
+/// values of the target type (e.g., 2 for `bool` or 256 for `u8`); together with the values and
+/// the variants being distinct, this ensures that the map is a bijection. Maps into `&str`,
+/// `u128`, or `i128` can never pass this check.
+///
+/// # Examples
+/// ```
+/// use bijective_enum_map::assert_bijective;
+/// enum Switch {
+///     On,
+///     Off,
+/// }
+///
+/// assert_bijective! {
+///     Switch, bool,
+///     On  <=> true,
+///     Off <=> false,
+/// }
+/// ```
+///
+/// ```compile_fail,E0080
+/// use bijective_enum_map::assert_bijective;
+/// enum AtMostTwo {
+///     Zero,
+///     One,
+///     Two,
+/// }
+///
+/// // error: the number of arms of a bijective map must equal the number of values of its target
+/// // type
+/// assert_bijective! {
+///     AtMostTwo, u8,
+///     Zero <=> 0,
+///     One  <=> 1,
+///     Two  <=> 2,
+/// }
+/// ```
+///
+/// [`assert_injective`]: crate::assert_injective
+/// [`bijective_enum_map`]: crate::bijective_enum_map
+#[macro_export]
+macro_rules! assert_bijective {
+    { $enum_ty:ty, $into:ty, $from:ty, $($body:tt)* } => {
+        $crate::__const_assert! { true bijective $enum_ty, $into, $($body)* }
+    };
+
+    { $enum_ty:ty, $into:ty, $from:ty } => {
+        $crate::__const_assert! { true bijective $enum_ty, $into, }
+    };
+
+    { $enum_ty:ty, $both:ty, $($body:tt)* } => {
+        $crate::__const_assert! { true bijective $enum_ty, $both, $($body)* }
+    };
+
+    { $enum_ty:ty, $both:ty } => {
+        $crate::__const_assert! { true bijective $enum_ty, $both, }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __const_assert {
+    { false $($rest:tt)* } => {};
+
+    {
+        true injective
+        $enum_ty:ty,
+        $ty:ty,
+        $($enum_variant:ident <=> $value:expr),*
+        $(,)?
+    } => {
+        $crate::__const_assert! { @variants $enum_ty, $($enum_variant),* }
+        $crate::__const_assert! { @distinct $ty, $($enum_variant <=> $value),* }
+    };
+
+    {
+        true bijective
+        $enum_ty:ty,
+        $ty:ty,
+        $($enum_variant:ident <=> $value:expr),*
+        $(,)?
+    } => {
+        $crate::__const_assert! { @variants $enum_ty, $($enum_variant),* }
+        $crate::__const_assert! { @distinct $ty, $($enum_variant <=> $value),* }
+
+        const _: () = ::core::assert!(
+            $crate::__private::is_cardinality(
+                $crate::__private::ValueKey::<$ty>::CARDINALITY,
+                $crate::__count_variants!($($enum_variant),*),
+            ),
+            "the number of arms of a bijective map must equal the number of values of its \
+             target type",
+        );
+    };
+
+    { @variants $enum_ty:ty $(,)? } => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn check_variants(value: $enum_ty) {
+                match value {}
+            }
+        };
+    };
+
+    { @variants $enum_ty:ty, $($enum_variant:ident),* } => {
+        const _: () = {
+            // Each variant of the enum must have exactly one arm.
+            #[allow(dead_code)]
+            fn check_variants(value: $enum_ty) {
+                $crate::__enum_ty_alias!($enum_ty);
+                match value {
+                    $( __EnumTy::$enum_variant => {} ),*
+                }
+            }
+
+            const NAMES: &[&str] = &[$( ::core::stringify!($enum_variant) ),*];
+
+            $(
+                const _: () = ::core::assert!(
+                    $crate::__private::arm_index(NAMES, ::core::stringify!($enum_variant))
+                        == $crate::__private::last_arm_index(
+                            NAMES,
+                            ::core::stringify!($enum_variant),
+                        ),
+                    ::core::concat!(
+                        "`", ::core::stringify!($enum_variant), "` has more than one arm",
+                    ),
+                );
+            )*
+        };
+    };
+
+    { @distinct $ty:ty $(,)? } => {};
+
+    {
+        @distinct
+        $ty:ty,
+        $($enum_variant:ident <=> $value:expr),*
+    } => {
+        const _: () = {
+            const NAMES: &[&str] = &[$( ::core::stringify!($enum_variant) ),*];
+            const KEYS: &[$crate::__private::Key<'static>] = &[
+                $( $crate::__private::ValueKey::<$ty>($value).key() ),*
+            ];
+
+            // Each assertion is a separate item, so that every repeated value is reported,
+            // together with the first arm which has the same value.
+            $(
+                const _: () = {
+                    const INDEX: usize =
+                        $crate::__private::arm_index(NAMES, ::core::stringify!($enum_variant));
+                    const EARLIER: usize = $crate::__private::earlier_equal(KEYS, INDEX);
+                    const PARTS: [&str; 5] = [
+                        "`", $crate::__private::arm_name(NAMES, EARLIER),
+                        "` and `", ::core::stringify!($enum_variant),
+                        "` map to the same value",
+                    ];
+                    const MESSAGE: $crate::__private::Message<
+                        { $crate::__private::message_len(&PARTS) },
+                    > = $crate::__private::Message::new(&PARTS);
+
+                    if EARLIER < INDEX {
+                        ::core::panic!("{}", MESSAGE.as_str());
+                    }
+                };
+            )*
+        };
+    };
+
+    { true $kind:ident $($rest:tt)* } => {
+        ::core::compile_error!(
+            "compile-time assertions require every arm to map a unit variant to a constant",
+        );
+    };
+
+    { $const_assert:tt $($rest:tt)* } => {
+        ::core::compile_error!(::core::concat!(
+            "expected `true` or `false` for `const_assert`, found `",
+            ::core::stringify!($const_assert), "`",
+        ));
+    };
+}
+
+/// A representation of a value which can be compared in const contexts.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub enum Key<'a> {
+    Integer(u128),
+    Bytes(&'a [u8]),
+}
+
+/// A wrapper around a value of a map, with inherent methods for each supported type of value.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct ValueKey<T>(pub T);
+
+macro_rules! impl_integer_value_keys {
+    { $($int:ty),* } => {
+        $(
+            impl ValueKey<$int> {
+                pub const CARDINALITY: Option<u128> = 1_u128.checked_shl(<$int>::BITS);
+
+                #[inline]
+                pub const fn key(self) -> Key<'static> {
+                    // Sign-extending signed integers is still injective.
+                    Key::Integer(self.0 as u128)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_value_keys! { u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize }
+
+impl ValueKey<bool> {
+    pub const CARDINALITY: Option<u128> = Some(2);
+
+    #[inline]
+    pub const fn key(self) -> Key<'static> {
+        Key::Integer(self.0 as u128)
+    }
+}
+
+impl ValueKey<char> {
+    // Every code point except for the surrogates.
+    pub const CARDINALITY: Option<u128> = Some(0x11_0000 - 0x800);
+
+    #[inline]
+    pub const fn key(self) -> Key<'static> {
+        Key::Integer(self.0 as u128)
+    }
+}
+
+impl<'a> ValueKey<&'a str> {
+    pub const CARDINALITY: Option<u128> = None;
+
+    #[inline]
+    pub const fn key(self) -> Key<'a> {
+        Key::Bytes(self.0.as_bytes())
+    }
+}
+
+const fn keys_equal(lhs: Key<'_>, rhs: Key<'_>) -> bool {
+    match (lhs, rhs) {
+        (Key::Integer(lhs), Key::Integer(rhs)) => lhs == rhs,
+        (Key::Bytes(lhs), Key::Bytes(rhs)) => {
+            if lhs.len() != rhs.len() {
+                return false;
+            }
+
+            let mut i = 0;
+            while i < lhs.len() {
+                if lhs[i] != rhs[i] {
+                    return false;
+                }
+                i += 1;
+            }
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Find the index of the first key before `index` which is equal to `keys[index]`, or `index` if
+/// there is none.
+#[doc(hidden)]
+#[must_use]
+pub const fn earlier_equal(keys: &[Key<'_>], index: usize) -> usize {
+    let mut i = 0;
+    while i < index {
+        if keys_equal(keys[i], keys[index]) {
+            return i;
+        }
+        i += 1;
+    }
+    index
+}
+
+/// Find the index of the first arm whose variant is named `name`, or `names.len()` if there is
+/// none. Arms are located by name so that a repeated variant is accepted, as in a `match`.
+#[doc(hidden)]
+#[must_use]
+pub const fn arm_index(names: &[&str], name: &str) -> usize {
+    let mut i = 0;
+    while i < names.len() {
+        if keys_equal(Key::Bytes(names[i].as_bytes()), Key::Bytes(name.as_bytes())) {
+            return i;
+        }
+        i += 1;
+    }
+    names.len()
+}
+
+/// Find the index of the last arm whose variant is named `name`, or `names.len()` if there is
+/// none.
+#[doc(hidden)]
+#[must_use]
+pub const fn last_arm_index(names: &[&str], name: &str) -> usize {
+    let mut i = names.len();
+    while i > 0 {
+        i -= 1;
+        if keys_equal(Key::Bytes(names[i].as_bytes()), Key::Bytes(name.as_bytes())) {
+            return i;
+        }
+    }
+    names.len()
+}
+
+/// Get the name of the arm at `index`, or an empty string if `index` is out of bounds.
+#[doc(hidden)]
+#[must_use]
+pub const fn arm_name<'a>(names: &[&'a str], index: usize) -> &'a str {
+    if index < names.len() {
+        names[index]
+    } else {
+        ""
+    }
+}
+
+/// The total length of `parts`, in bytes.
+#[doc(hidden)]
+#[must_use]
+pub const fn message_len(parts: &[&str]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        len += parts[i].len();
+        i += 1;
+    }
+    len
+}
+
+/// An error message concatenated at compile time, which can name arms which are only known
+/// during const evaluation.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct Message<const N: usize>([u8; N]);
+
+impl<const N: usize> Message<N> {
+    /// Concatenate `parts`, whose total length should be `N`; otherwise, the message is empty
+    /// (or, more precisely, consists of `N` null characters).
+    #[must_use]
+    pub const fn new(parts: &[&str]) -> Self {
+        let mut bytes = [0; N];
+        if message_len(parts) != N {
+            return Self(bytes);
+        }
+
+        let mut len = 0;
+        let mut i = 0;
+        while i < parts.len() {
+            let part = parts[i].as_bytes();
+            let mut j = 0;
+            while j < part.len() {
+                bytes[len] = part[j];
+                len += 1;
+                j += 1;
+            }
+            i += 1;
+        }
+
+        Self(bytes)
+    }
+
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        // SAFETY: the bytes are either all zero, or a concatenation of `str`s, and are thus
+        // valid UTF-8.
+        unsafe { core::str::from_utf8_unchecked(&self.0) }
+    }
+}
+
+/// Find the index of the first offset which prevents `offsets` from being exactly the integers
+/// `0..offsets.len()` (in increasing order if `in_order` is set, and in any order otherwise), or
+/// `offsets.len()` if there is none.
+#[doc(hidden)]
+#[must_use]
+pub const fn ordinal_range_error(offsets: &[u128], in_order: bool) -> usize {
+    let len = offsets.len() as u128;
+
+    let mut i = 0;
+    while i < offsets.len() {
+        if offsets[i] >= len || (in_order && offsets[i] != i as u128) {
+            return i;
+        }
+
+        // Offsets which are in order are already distinct.
+        let mut j = 0;
+        while !in_order && j < i {
+            if offsets[j] == offsets[i] {
+                return i;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+
+    offsets.len()
+}
+
+/// Determine whether `offsets` are exactly the integers `0..offsets.len()` (in increasing order
+/// if `in_order` is set, and in any order otherwise).
+#[doc(hidden)]
+#[must_use]
+pub const fn is_ordinal_range(offsets: &[u128], in_order: bool) -> bool {
+    ordinal_range_error(offsets, in_order) == offsets.len()
+}
+
+#[doc(hidden)]
+#[must_use]
+pub const fn is_cardinality(cardinality: Option<u128>, count: usize) -> bool {
+    match cardinality {
+        Some(cardinality) => cardinality == count as u128,
+        None              => false,
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{bijective_enum_map, injective_enum_map};
+
+    #[allow(dead_code)]
+    enum Empty {}
+
+    #[allow(dead_code)]
+    enum Strings {
+        A,
+        B,
+        C,
+        D,
+        E,
+    }
+
+    #[allow(dead_code)]
+    enum Signed {
+        Negative,
+        Zero,
+        Max,
+        Min,
+    }
+
+    #[allow(dead_code)]
+    enum Chars {
+        A,
+        B,
+    }
+
+    #[allow(dead_code)]
+    enum Power {
+        On,
+        Off,
+    }
+
+    assert_injective! { Empty, u8 }
+
+    assert_injective! {
+        Strings, &'static str, &str,
+        A <=> "",
+        B <=> "a",
+        C <=> "b",
+        D <=> "ab",
+        E <=> "ba",
+    }
+
+    assert_injective! {
+        Signed, i16,
+        Negative <=> -1,
+        Zero     <=> 0,
+        Max      <=> i16::MAX,
+        Min      <=> i16::MIN,
+    }
+
+    assert_injective! {
+        Chars, char,
+        A <=> 'a',
+        B <=> 'b',
+    }
+
+    assert_bijective! {
+        Power, bool,
+        On  <=> true,
+        Off <=> false,
+    }
+
+    #[test]
+    fn const_assert_option() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum AtMostTwo {
+            Zero,
+            One,
+            Two,
+        }
+
+        #[derive(Debug, PartialEq, Eq)]
+        enum Switch {
+            On,
+            Off,
+        }
+
+        injective_enum_map! {
+            AtMostTwo, &'static str, &str,
+            const_assert = true,
+            Zero <=> "zero",
+            One  <=> "one",
+            Two  <=> "two",
+        }
+
+        bijective_enum_map! {
+            Switch, bool,
+            const_assert = true,
+            mode = tables,
+            On  <=> true,
+            Off <=> false,
+        }
+
+        assert_eq!(AtMostTwo::try_from("two"), Ok(AtMostTwo::Two));
+        assert_eq!(Switch::from(false), Switch::Off);
+    }
+}
+
+#[cfg(doctest)]
+pub mod compile_fail_tests {
+    /// ```compile_fail,E0080
+    /// use bijective_enum_map::assert_injective;
+    /// enum Strings {
+    ///     A,
+    ///     B,
+    ///     C,
+    /// }
+    ///
+    /// assert_injective! {
+    ///     Strings, &'static str,
+    ///     A <=> "abc",
+    ///     B <=> "ab",
+    ///     C <=> "abc",
+    /// }
+    /// ```
+    pub fn _repeated_string() {}
+
+    /// ```compile_fail,E0080
+    /// use bijective_enum_map::assert_injective;
+    /// enum Signed {
+    ///     A,
+    ///     B,
+    ///     C,
+    /// }
+    ///
+    /// assert_injective! {
+    ///     Signed, i8,
+    ///     A <=> -1,
+    ///     B <=> 0,
+    ///     C <=> -0,
+    /// }
+    /// ```
+    pub fn _repeated_integer() {}
+
+    /// ```compile_fail,E0080
+    /// use bijective_enum_map::injective_enum_map;
+    /// enum AtMostTwo {
+    ///     Zero,
+    ///     One,
+    ///     Two,
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     AtMostTwo, u8,
+    ///     const_assert = true,
+    ///     Zero <=> 0,
+    ///     One  <=> 1,
+    ///     Two  <=> 0,
+    /// }
+    /// ```
+    pub fn _const_assert_option() {}
+
+    /// ```compile_fail,E0080
+    /// use bijective_enum_map::assert_bijective;
+    /// enum Strings {
+    ///     A,
+    /// }
+    ///
+    /// assert_bijective! {
+    ///     Strings, &'static str,
+    ///     A <=> "a",
+    /// }
+    /// ```
+    pub fn _bijective_string() {}
+
+    /// ```compile_fail,E0004
+    /// use bijective_enum_map::assert_injective;
+    /// enum AtMostTwo {
+    ///     Zero,
+    ///     One,
+    ///     Two,
+    /// }
+    ///
+    /// assert_injective! {
+    ///     AtMostTwo, u8,
+    ///     Zero <=> 0,
+    ///     One  <=> 1,
+    /// }
+    /// ```
+    pub fn _missing_variant() {}
+
+    /// ```compile_fail,E0599
+    /// use bijective_enum_map::assert_injective;
+    /// enum AtMostTwo {
+    ///     Zero,
+    ///     One,
+    ///     Two,
+    /// }
+    ///
+    /// assert_injective! {
+    ///     AtMostTwo, u8,
+    ///     Zero  <=> 0,
+    ///     One   <=> 1,
+    ///     Two   <=> 2,
+    ///     Three <=> 3,
+    /// }
+    /// ```
+    pub fn _unknown_variant() {}
+
+    /// ```compile_fail,E0080
+    /// use bijective_enum_map::assert_bijective;
+    /// enum Switch {
+    ///     On,
+    ///     Off,
+    /// }
+    ///
+    /// assert_bijective! {
+    ///     Switch, bool,
+    ///     On  <=> true,
+    ///     On  <=> false,
+    ///     Off <=> false,
+    /// }
+    /// ```
+    pub fn _repeated_variant() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::assert_injective;
+    /// enum Floats {
+    ///     A,
+    /// }
+    ///
+    /// assert_injective! {
+    ///     Floats, f32,
+    ///     A <=> 1.0,
+    /// }
+    /// ```
+    pub fn _unsupported_type() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::assert_injective;
+    /// enum Nonunit {
+    ///     Something(u8),
+    /// }
+    ///
+    /// assert_injective! {
+    ///     Nonunit, u8,
+    ///     Something(num) <=> num,
+    /// }
+    /// ```
+    pub fn _non_unit_variant() {}
+}
@@ -0,0 +1,382 @@
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_contiguous {
+    {
+        @check
+        $enum_ty:ty,
+        $into:ty,
+        $($enum_variant:ident <=> $value:expr),+
+    } => {
+        const _: () = {
+            const FIRST: $into = [$( $value ),+][0];
+            const NAMES: &[&str] = &[$( ::core::stringify!($enum_variant) ),+];
+            const OFFSETS: &[u128] = &[$(
+                { let value: $into = $value; (value as u128).wrapping_sub(FIRST as u128) }
+            ),+];
+            const OUT_OF_ORDER: usize = $crate::__private::ordinal_range_error(OFFSETS, true);
+
+            // Each assertion is a separate item, so that every arm which does not map to its
+            // discriminant is reported, together with the first arm which is out of order.
+            $(
+                // Casting the discriminant to `$into` could truncate it, so both sides are
+                // compared in a type wide enough for either.
+                const _: () = ::core::assert!(
+                    <$enum_ty>::$enum_variant as i128
+                        == { let value: $into = $value; value as i128 },
+                    ::core::concat!(
+                        "`mode = contiguous` requires `", ::core::stringify!($enum_variant),
+                        "` to map to its discriminant",
+                    ),
+                );
+
+                const _: () = ::core::assert!(
+                    $crate::__private::arm_index(NAMES, ::core::stringify!($enum_variant))
+                        != OUT_OF_ORDER,
+                    ::core::concat!(
+                        "`mode = contiguous` requires the arms to be in order of consecutive \
+                         values, but `", ::core::stringify!($enum_variant), "` is out of order",
+                    ),
+                );
+            )+
+
+            // The cast in the `From` conversion does not check that every variant is mapped.
+            #[allow(dead_code)]
+            fn check_variants(value: $enum_ty) {
+                $crate::__enum_ty_alias!($enum_ty);
+                #[warn(unreachable_patterns)]
+                match value {
+                    $( __EnumTy::$enum_variant => {} ),+
+                }
+            }
+        };
+    };
+
+    {
+        @from
+        [$inline:meta]
+        $enum_ty:ty,
+        $into:ty,
+        $($enum_variant:ident <=> $value:expr),+
+    } => {
+        #[doc = "Maps each variant of the enum into a value:"]
+        #[doc = ""]
+        $(
+            #[doc = ::core::concat!(
+                "- `", ::core::stringify!($enum_variant),
+                "` => `", ::core::stringify!($value), "`",
+            )]
+        )+
+        impl ::core::convert::From<$enum_ty> for $into {
+            #[$inline]
+            fn from(value: $enum_ty) -> Self {
+                // The discriminants are checked to equal the values.
+                value as $into
+            }
+        }
+    };
+
+    {
+        [$inline:meta]
+        [$error_ty:ty = $error:expr]
+        injective
+        $enum_ty:ty,
+        $into:ty,
+        $try_from:ty,
+        $($enum_variant:ident <=> $value:expr),+
+        $(,)?
+    } => {
+        $crate::__impl_contiguous! { @check $enum_ty, $into, $($enum_variant <=> $value),+ }
+        $crate::__impl_contiguous! {
+            @from [$inline] $enum_ty, $into, $($enum_variant <=> $value),+
+        }
+
+        const _: () = {
+            const FIRST: $try_from = [$( $value ),+][0];
+            const VARIANTS: [$enum_ty; $crate::__count_variants!($($enum_variant),+)] = [
+                $( <$enum_ty>::$enum_variant ),+
+            ];
+
+            #[doc = "Maps values into variants of the enum, and fails on any other value:"]
+            #[doc = ""]
+            $(
+                #[doc = ::core::concat!(
+                    "- `", ::core::stringify!($value),
+                    "` => `", ::core::stringify!($enum_variant), "`",
+                )]
+            )+
+            impl ::core::convert::TryFrom<$try_from> for $enum_ty {
+                type Error = $error_ty;
+
+                #[$inline]
+                fn try_from(value: $try_from) -> Result<Self, $error_ty> {
+                    // Values less than `FIRST` wrap around to a large offset.
+                    let offset = (value as u128).wrapping_sub(FIRST as u128);
+                    if offset < VARIANTS.len() as u128 {
+                        Ok(VARIANTS[offset as usize])
+                    } else {
+                        Err($error)
+                    }
+                }
+            }
+        };
+    };
+
+    {
+        [$inline:meta]
+        [default]
+        bijective
+        $enum_ty:ty,
+        $into:ty,
+        $from:ty,
+        $($enum_variant:ident <=> $value:expr),+
+        $(,)?
+    } => {
+        $crate::__impl_contiguous! { @check $enum_ty, $into, $($enum_variant <=> $value),+ }
+        $crate::__impl_contiguous! {
+            @from [$inline] $enum_ty, $into, $($enum_variant <=> $value),+
+        }
+        $crate::__check_patterns! { exhaustive $from, $($enum_variant <=> $value),+ }
+
+        const _: () = {
+            const FIRST: $from = [$( $value ),+][0];
+            const VARIANTS: [$enum_ty; $crate::__count_variants!($($enum_variant),+)] = [
+                $( <$enum_ty>::$enum_variant ),+
+            ];
+
+            #[doc = "Maps values into variants of the enum:"]
+            #[doc = ""]
+            $(
+                #[doc = ::core::concat!(
+                    "- `", ::core::stringify!($value),
+                    "` => `", ::core::stringify!($enum_variant), "`",
+                )]
+            )+
+            impl ::core::convert::From<$from> for $enum_ty {
+                #[$inline]
+                fn from(value: $from) -> Self {
+                    // The values are checked to be exhaustive, so the offset is always in bounds.
+                    VARIANTS[(value as u128).wrapping_sub(FIRST as u128) as usize]
+                }
+            }
+        };
+    };
+
+    {
+        [$inline:meta] [$error_ty:ty = $error:expr] injective
+        $enum_ty:ty, $into:ty, $try_from:ty $(,)?
+    } => {
+        $crate::__impl_from_enum! { [$inline] $enum_ty, $into }
+        $crate::__impl_enum_try_from! { [$inline] [$error_ty = $error] $enum_ty, $try_from }
+    };
+
+    { [$inline:meta] [default] bijective $enum_ty:ty, $into:ty, $from:ty $(,)? } => {
+        $crate::__impl_from_enum! { [$inline] $enum_ty, $into }
+        $crate::__impl_enum_from! { [$inline] $enum_ty, $from }
+    };
+
+    { $($tokens:tt)* } => {
+        ::core::compile_error!(
+            "`mode = contiguous` requires every arm to map a unit variant to a constant",
+        );
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{injective_enum_map, NoMatchingVariant};
+
+    #[test]
+    fn offset_values() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr(u8)]
+        enum Opcode {
+            Load = 0x10,
+            Store,
+            Jump,
+        }
+
+        injective_enum_map! {
+            Opcode, u8,
+            mode = contiguous,
+            error: NoMatchingVariant,
+            Load  <=> 0x10,
+            Store <=> 0x11,
+            Jump  <=> 0x12,
+        }
+
+        assert_eq!(u8::from(Opcode::Load), 0x10);
+        assert_eq!(u8::from(Opcode::Jump), 0x12);
+        assert_eq!(Opcode::try_from(0x11), Ok(Opcode::Store));
+        assert_eq!(Opcode::try_from(0x0F), Err(NoMatchingVariant));
+        assert_eq!(Opcode::try_from(0x13), Err(NoMatchingVariant));
+        assert_eq!(Opcode::try_from(0x00), Err(NoMatchingVariant));
+        assert_eq!(Opcode::try_from(u8::MAX), Err(NoMatchingVariant));
+    }
+
+    #[test]
+    fn negative_values() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr(i8)]
+        enum Sign {
+            Negative = -1,
+            Zero,
+            Positive,
+        }
+
+        injective_enum_map! {
+            Sign, i8,
+            mode = contiguous,
+            Negative <=> -1,
+            Zero     <=> 0,
+            Positive <=> 1,
+        }
+
+        assert_eq!(i8::from(Sign::Negative), -1);
+        assert_eq!(Sign::try_from(-1), Ok(Sign::Negative));
+        assert_eq!(Sign::try_from(1), Ok(Sign::Positive));
+        assert_eq!(Sign::try_from(-2), Err(()));
+        assert_eq!(Sign::try_from(i8::MIN), Err(()));
+        assert_eq!(Sign::try_from(i8::MAX), Err(()));
+    }
+
+    #[test]
+    fn wider_repr() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr(u16)]
+        enum Port {
+            Http = 80,
+            Auth = 81,
+        }
+
+        injective_enum_map! {
+            Port, u16,
+            mode = contiguous,
+            inline = always,
+            Http <=> 80,
+            Auth <=> 81,
+        }
+
+        assert_eq!(u16::from(Port::Auth), 81);
+        assert_eq!(Port::try_from(80), Ok(Port::Http));
+        assert_eq!(Port::try_from(82), Err(()));
+    }
+
+    #[test]
+    fn empty() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Empty {}
+
+        injective_enum_map! { Empty, u8, mode = contiguous }
+
+        assert_eq!(Empty::try_from(0), Err(()));
+    }
+}
+
+#[cfg(doctest)]
+pub mod compile_fail_tests {
+    /// ```compile_fail,E0080
+    /// use bijective_enum_map::injective_enum_map;
+    /// #[derive(Clone, Copy)]
+    /// #[repr(u8)]
+    /// enum Opcode {
+    ///     Load = 0x10,
+    ///     Store,
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     Opcode, u8,
+    ///     mode = contiguous,
+    ///     Load  <=> 0x10,
+    ///     Store <=> 0x12,
+    /// }
+    /// ```
+    pub fn _not_discriminant() {}
+
+    /// ```compile_fail,E0080
+    /// use bijective_enum_map::injective_enum_map;
+    /// #[derive(Clone, Copy)]
+    /// #[repr(u16)]
+    /// enum Wide {
+    ///     A = 0x100,
+    ///     B = 0x101,
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     Wide, u8,
+    ///     mode = contiguous,
+    ///     A <=> 0,
+    ///     B <=> 1,
+    /// }
+    /// ```
+    pub fn _truncated_discriminant() {}
+
+    /// ```compile_fail,E0080
+    /// use bijective_enum_map::injective_enum_map;
+    /// #[derive(Clone, Copy)]
+    /// #[repr(u8)]
+    /// enum Opcode {
+    ///     Load = 0x10,
+    ///     Store,
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     Opcode, u8,
+    ///     mode = contiguous,
+    ///     Store <=> 0x11,
+    ///     Load  <=> 0x10,
+    /// }
+    /// ```
+    pub fn _out_of_order() {}
+
+    /// ```compile_fail,E0004
+    /// use bijective_enum_map::injective_enum_map;
+    /// #[derive(Clone, Copy)]
+    /// #[repr(u8)]
+    /// enum Opcode {
+    ///     Load,
+    ///     Store,
+    ///     Jump,
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     Opcode, u8,
+    ///     mode = contiguous,
+    ///     Load  <=> 0,
+    ///     Store <=> 1,
+    /// }
+    /// ```
+    pub fn _missing_variant() {}
+
+    /// ```compile_fail,E0004
+    /// use bijective_enum_map::bijective_enum_map;
+    /// #[derive(Clone, Copy)]
+    /// #[repr(u8)]
+    /// enum Opcode {
+    ///     Load,
+    ///     Store,
+    /// }
+    ///
+    /// bijective_enum_map! {
+    ///     Opcode, u8,
+    ///     mode = contiguous,
+    ///     Load  <=> 0,
+    ///     Store <=> 1,
+    /// }
+    /// ```
+    pub fn _bijective_not_surjective() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::injective_enum_map;
+    /// #[derive(Clone, Copy)]
+    /// enum Nonunit {
+    ///     Something(u8),
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     Nonunit, u8,
+    ///     mode = contiguous,
+    ///     Something(n) <=> n,
+    /// }
+    /// ```
+    pub fn _non_unit_variant() {}
+}
@@ -0,0 +1,159 @@
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_cow {
+    {
+        @from
+        [$inline:meta]
+        $enum_ty:ty,
+        $into:ty,
+        $(
+            $enum_variant:ident$(($($tuple:tt)*))?$({$($struct:tt)*})?
+            <=> $value:expr $(=> $pattern:pat)?
+        ),+
+        $(,)?
+    } => {
+        #[doc = "Maps each variant of the enum into a value:"]
+        #[doc = ""]
+        $(
+            #[doc = ::core::concat!(
+                "- `", ::core::stringify!($enum_variant),
+                $( "(", ::core::stringify!($($tuple)*), ")", )?
+                $( " { ", ::core::stringify!($($struct)*), " }", )?
+                "` => `", ::core::stringify!($value), "`",
+            )]
+        )+
+        impl ::core::convert::From<$enum_ty> for $into {
+            #[$inline]
+            fn from(value: $enum_ty) -> Self {
+                $crate::__enum_ty_alias!($enum_ty);
+                #[warn(unreachable_patterns)]
+                let value = match value {
+                    $( __EnumTy::$enum_variant$(($($tuple)*))?$({$($struct)*})? => $value ),+
+                };
+                $crate::__private::Cow::Borrowed(value)
+            }
+        }
+    };
+
+    { @from [$inline:meta] $enum_ty:ty, $into:ty $(,)? } => {
+        $crate::__impl_from_enum! { [$inline] $enum_ty, $into }
+    };
+
+    {
+        [$inline:meta]
+        [$error_ty:ty = $error:expr]
+        $enum_ty:ty,
+        $into:ty
+        $(, $($body:tt)*)?
+    } => {
+        $crate::__impl_cow! { @from [$inline] $enum_ty, $into, $($($body)*)? }
+        $crate::__impl_enum_try_from! {
+            [$inline] [$error_ty = $error] $enum_ty, &str, $($($body)*)?
+        }
+
+        impl ::core::convert::TryFrom<$crate::__private::Cow<'_, str>> for $enum_ty {
+            type Error = $error_ty;
+
+            #[$inline]
+            fn try_from(value: $crate::__private::Cow<'_, str>) -> Result<Self, $error_ty> {
+                <Self as ::core::convert::TryFrom<&str>>::try_from(&*value)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_cow {
+    { $($tokens:tt)* } => {
+        ::core::compile_error!("`mode = cow` requires the `alloc` feature");
+    };
+}
+
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::{borrow::Cow, string::String};
+
+    use crate::injective_enum_map;
+
+    #[test]
+    fn cow_mode() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Color {
+            Red,
+            Green,
+            Blue,
+        }
+
+        injective_enum_map! {
+            Color, Cow<'static, str>,
+            mode = cow,
+            Red   <=> "red",
+            Green <=> "green",
+            Blue  <=> "blue",
+        }
+
+        assert!(matches!(Cow::from(Color::Green), Cow::Borrowed("green")));
+        assert_eq!(Color::try_from("blue"), Ok(Color::Blue));
+        assert_eq!(Color::try_from("purple"), Err(()));
+        assert_eq!(Color::try_from(Cow::Borrowed("red")), Ok(Color::Red));
+        assert_eq!(Color::try_from(Cow::<str>::Owned(String::from("green"))), Ok(Color::Green));
+
+        let owned = String::from("blue");
+        assert_eq!(Color::try_from(owned.as_str()), Ok(Color::Blue));
+    }
+
+    #[test]
+    fn with_error() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Switch {
+            On,
+            Off,
+        }
+
+        injective_enum_map! {
+            Switch, Cow<'static, str>,
+            mode = cow,
+            error: &'static str = "expected `on` or `off`",
+            On  <=> "on",
+            Off <=> "off",
+        }
+
+        assert_eq!(Switch::try_from("off"), Ok(Switch::Off));
+        assert_eq!(Switch::try_from(Cow::Borrowed("of")), Err("expected `on` or `off`"));
+    }
+
+    #[test]
+    fn expected_values() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Switch {
+            On,
+            Off,
+        }
+
+        injective_enum_map! {
+            Switch, Cow<'static, str>,
+            mode = cow,
+            expected_values = true,
+            On  <=> "on",
+            Off <=> "off",
+        }
+
+        let expected: &'static [&'static str] = Switch::expected_values();
+        assert_eq!(expected, ["on", "off"]);
+    }
+
+    #[test]
+    fn empty() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Empty {}
+
+        injective_enum_map! { Empty, Cow<'static, str>, mode = cow }
+
+        assert_eq!(Empty::try_from("anything"), Err(()));
+        assert_eq!(Empty::try_from(Cow::Borrowed("")), Err(()));
+    }
+}
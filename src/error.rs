@@ -0,0 +1,210 @@
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+
+/// An error indicating that a value is not mapped to any variant of an enum.
+///
+/// By default, the `TryFrom` conversions of [`injective_enum_map`] use `()` as their error type;
+/// the `error: NoMatchingVariant` option uses this type instead, which implements [`Display`]
+/// and (with the `core_error` feature) [`core::error::Error`], so that it can be propagated with
+/// `?` into other error types.
+///
+/// # Example
+/// ```
+/// use bijective_enum_map::{injective_enum_map, NoMatchingVariant};
+/// #[derive(Debug, PartialEq, Eq)]
+/// enum AtMostTwo {
+///     Zero,
+///     One,
+///     Two,
+/// }
+///
+/// injective_enum_map! {
+///     AtMostTwo, u8,
+///     error: NoMatchingVariant,
+///     Zero <=> 0,
+///     One  <=> 1,
+///     Two  <=> 2,
+/// }
+///
+/// assert_eq!(AtMostTwo::try_from(1_u8), Ok(AtMostTwo::One));
+/// assert_eq!(AtMostTwo::try_from(3_u8), Err(NoMatchingVariant));
+/// ```
+///
+/// [`injective_enum_map`]: crate::injective_enum_map
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NoMatchingVariant;
+
+impl Display for NoMatchingVariant {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("the value does not match any variant of the enum")
+    }
+}
+
+#[cfg(feature = "core_error")]
+impl core::error::Error for NoMatchingVariant {}
+
+/// An error indicating that a buffer is too short to hold the bytes of a value.
+///
+/// This is returned by the `write_to` function generated by the `bytes` option of
+/// [`injective_enum_map`] and [`bijective_enum_map`], and is also a variant of [`ReadError`].
+///
+/// [`injective_enum_map`]: crate::injective_enum_map
+/// [`bijective_enum_map`]: crate::bijective_enum_map
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BufferTooShort;
+
+impl Display for BufferTooShort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("the buffer is too short to hold the bytes of the value")
+    }
+}
+
+#[cfg(feature = "core_error")]
+impl core::error::Error for BufferTooShort {}
+
+/// An error from the `try_read_from` function generated by the `bytes` option of
+/// [`injective_enum_map`] and [`bijective_enum_map`].
+///
+/// `E` is the error type of the `TryFrom` conversion into the enum.
+///
+/// # Example
+/// ```
+/// use bijective_enum_map::{injective_enum_map, BufferTooShort, NoMatchingVariant, ReadError};
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum Kind {
+///     Request,
+///     Response,
+/// }
+///
+/// injective_enum_map! {
+///     Kind, u16,
+///     bytes = be,
+///     error: NoMatchingVariant,
+///     Request  <=> 0x0100,
+///     Response <=> 0x0200,
+/// }
+///
+/// assert_eq!(Kind::try_read_from(&[0x02, 0x00, 0xFF]), Ok((Kind::Response, &[0xFF][..])));
+/// assert_eq!(Kind::try_read_from(&[0x01]), Err(ReadError::BufferTooShort(BufferTooShort)));
+/// assert_eq!(
+///     Kind::try_read_from(&[0x00, 0x01]),
+///     Err(ReadError::NoMatchingVariant(NoMatchingVariant)),
+/// );
+/// ```
+///
+/// [`injective_enum_map`]: crate::injective_enum_map
+/// [`bijective_enum_map`]: crate::bijective_enum_map
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ReadError<E> {
+    /// There were fewer bytes than the size of the target type of the map.
+    BufferTooShort(BufferTooShort),
+    /// The value read from the bytes does not match any variant of the enum.
+    NoMatchingVariant(E),
+}
+
+// The wrapped error is reported by `source`, so it is not repeated in the message.
+impl<E> Display for ReadError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(match self {
+            Self::BufferTooShort(_)    => "there are too few bytes to read the value from",
+            Self::NoMatchingVariant(_) => "the bytes do not hold a value matching any variant",
+        })
+    }
+}
+
+#[cfg(feature = "core_error")]
+impl<E: core::error::Error + 'static> core::error::Error for ReadError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::BufferTooShort(error)    => Some(error),
+            Self::NoMatchingVariant(error) => Some(error),
+        }
+    }
+}
+
+impl<E> From<BufferTooShort> for ReadError<E> {
+    #[inline]
+    fn from(error: BufferTooShort) -> Self {
+        Self::BufferTooShort(error)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::injective_enum_map;
+    use super::NoMatchingVariant;
+
+    #[test]
+    fn error_option() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Switch {
+            On,
+            Off,
+        }
+
+        injective_enum_map! {
+            Switch, &'static str, &str,
+            error: NoMatchingVariant,
+            On  <=> "on",
+            Off <=> "off",
+        }
+        injective_enum_map! {
+            Switch, u8,
+            error: super::NoMatchingVariant = super::NoMatchingVariant,
+            mode = tables,
+            On  <=> 1,
+            Off <=> 0,
+        }
+
+        assert_eq!(Switch::try_from("on"), Ok(Switch::On));
+        assert_eq!(Switch::try_from("of"), Err(NoMatchingVariant));
+        assert_eq!(Switch::try_from(2_u8), Err(NoMatchingVariant));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn display() {
+        assert_eq!(
+            alloc::format!("{NoMatchingVariant}"),
+            "the value does not match any variant of the enum",
+        );
+        let error = super::ReadError::<NoMatchingVariant>::from(super::BufferTooShort);
+        assert_eq!(
+            alloc::format!("{error}"),
+            "there are too few bytes to read the value from",
+        );
+    }
+
+    #[cfg(all(feature = "core_error", feature = "alloc"))]
+    #[test]
+    fn error_source() {
+        use alloc::{string::ToString, vec::Vec};
+
+        fn chain(error: &dyn core::error::Error) -> Vec<alloc::string::String> {
+            let mut messages = Vec::new();
+            let mut next = Some(error);
+            while let Some(error) = next {
+                messages.push(error.to_string());
+                next = error.source();
+            }
+            messages
+        }
+
+        assert_eq!(chain(&NoMatchingVariant), ["the value does not match any variant of the enum"]);
+        assert_eq!(
+            chain(&super::ReadError::NoMatchingVariant(NoMatchingVariant)),
+            [
+                "the bytes do not hold a value matching any variant",
+                "the value does not match any variant of the enum",
+            ],
+        );
+        assert_eq!(
+            chain(&super::ReadError::<NoMatchingVariant>::from(super::BufferTooShort)),
+            [
+                "there are too few bytes to read the value from",
+                "the buffer is too short to hold the bytes of the value",
+            ],
+        );
+    }
+}
@@ -0,0 +1,128 @@
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_expected_values {
+    { false $($rest:tt)* } => {};
+
+    {
+        true [$vis:vis] [$inline:meta] cow $enum_ty:ty, $into:ty $(, $($body:tt)*)?
+    } => {
+        $crate::__impl_expected_values! {
+            @impl [$vis] [$inline] $enum_ty, &'static str, $($($body)*)?
+        }
+    };
+
+    {
+        true [$vis:vis] [$inline:meta] $mode:tt $enum_ty:ty, $into:ty $(, $($body:tt)*)?
+    } => {
+        $crate::__impl_expected_values! {
+            @impl [$vis] [$inline] $enum_ty, $into, $($($body)*)?
+        }
+    };
+
+    {
+        @impl [$vis:vis] [$inline:meta]
+        $enum_ty:ty,
+        $into:ty,
+        $(
+            $enum_variant:ident$(($($tuple:tt)*))?$({$($struct:tt)*})?
+            <=> $value:expr $(=> $pattern:pat)?
+        ),*
+        $(,)?
+    } => {
+        impl $enum_ty {
+            #[doc = "The values which the enum maps into, in the order of the arms of its map:"]
+            #[doc = ""]
+            $( #[doc = ::core::concat!("- `", ::core::stringify!($value), "`")] )*
+            #[$inline]
+            #[must_use]
+            $vis fn expected_values() -> &'static [$into] {
+                const VALUES: &[$into] = &[$( $value ),*];
+                VALUES
+            }
+        }
+    };
+
+    { $expected_values:tt $($rest:tt)* } => {
+        ::core::compile_error!(::core::concat!(
+            "expected `true` or `false` for `expected_values`, found `",
+            ::core::stringify!($expected_values), "`",
+        ));
+    };
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::injective_enum_map;
+
+    #[test]
+    fn strings() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Format {
+            Json,
+            Toml,
+            Yaml,
+        }
+
+        injective_enum_map! {
+            Format, &'static str, &str,
+            expected_values = true,
+            Json <=> "json",
+            Toml <=> "toml",
+            Yaml <=> "yaml",
+        }
+
+        assert_eq!(Format::expected_values(), ["json", "toml", "yaml"]);
+        assert_eq!(Format::try_from("ini"), Err(()));
+    }
+
+    #[test]
+    fn non_unit_and_pattern_arms() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Level {
+            Low,
+            Medium(()),
+            High { unit: () },
+        }
+
+        injective_enum_map! {
+            Level, u8,
+            expected_values = true,
+            Low               <=> 0 => 0..=9,
+            Medium(())        <=> 10,
+            High { unit: () } <=> 20 => 20..=u8::MAX,
+        }
+
+        // Only the canonical values are listed, though other values match the patterns.
+        assert_eq!(Level::expected_values(), [0, 10, 20]);
+        assert_eq!(Level::try_from(5), Ok(Level::Low));
+        assert!(!Level::expected_values().contains(&5));
+    }
+
+    #[test]
+    fn empty() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Empty {}
+
+        injective_enum_map! { Empty, u8, expected_values = true }
+
+        assert!(Empty::expected_values().is_empty());
+    }
+}
+
+#[cfg(doctest)]
+pub mod compile_fail_tests {
+    /// ```compile_fail
+    /// use bijective_enum_map::injective_enum_map;
+    /// enum Nonconstant {
+    ///     Something(u8),
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     Nonconstant, u8,
+    ///     expected_values = true,
+    ///     Something(n) <=> n,
+    /// }
+    /// ```
+    pub fn _non_constant_value() {}
+}
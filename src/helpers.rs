@@ -1,53 +1,50 @@
+// This is because we can't do <$enum_ty>::$enum_variant in a pattern
+// (that syntax is unstable/experimental in that position)
 #[cfg(feature = "use_type_as")]
 #[doc(hidden)]
 #[macro_export]
-macro_rules! __impl_from_enum {
-    {
-        $enum_ty:ty,
-        $into:ty,
-        $($enum_variant:ident$(($($tuple:tt)*))?$({$($struct:tt)*})? <=> $value:expr),+
-        $(,)?
-    } => {
-        impl ::core::convert::From<$enum_ty> for $into {
-            #[inline]
-            fn from(value: $enum_ty) -> Self {
-                // This is because we can't do <$enum_ty>::$enum_variant
-                // (that syntax is unstable/experimental in that position)
-                use $enum_ty as __EnumTy;
-                #[warn(unreachable_patterns)]
-                match value {
-                    $( __EnumTy::$enum_variant$(($($tuple)*))?$({$($struct)*})? => $value ),+
-                }
-            }
-        }
+macro_rules! __enum_ty_alias {
+    { $enum_ty:ty } => {
+        use $enum_ty as __EnumTy;
     };
+}
 
-    { $enum_ty:ty, $into:ty $(,)? } => {
-        impl ::core::convert::From<$enum_ty> for $into {
-            #[inline]
-            fn from(value: $enum_ty) -> Self {
-                match value {}
-            }
-        }
+#[cfg(not(feature = "use_type_as"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_ty_alias {
+    { $enum_ty:ty } => {
+        type __EnumTy = $enum_ty;
     };
 }
 
-#[cfg(not(feature = "use_type_as"))]
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __impl_from_enum {
     {
+        [$inline:meta]
         $enum_ty:ty,
         $into:ty,
-        $($enum_variant:ident$(($($tuple:tt)*))?$({$($struct:tt)*})? <=> $value:expr),+
+        $(
+            $enum_variant:ident$(($($tuple:tt)*))?$({$($struct:tt)*})?
+            <=> $value:expr $(=> $pattern:pat)?
+        ),+
         $(,)?
     } => {
+        #[doc = "Maps each variant of the enum into a value:"]
+        #[doc = ""]
+        $(
+            #[doc = ::core::concat!(
+                "- `", ::core::stringify!($enum_variant),
+                $( "(", ::core::stringify!($($tuple)*), ")", )?
+                $( " { ", ::core::stringify!($($struct)*), " }", )?
+                "` => `", ::core::stringify!($value), "`",
+            )]
+        )+
         impl ::core::convert::From<$enum_ty> for $into {
-            #[inline]
+            #[$inline]
             fn from(value: $enum_ty) -> Self {
-                // This is because we can't do <$enum_ty>::$enum_variant
-                // (that syntax is unstable/experimental in that position)
-                type __EnumTy = $enum_ty;
+                $crate::__enum_ty_alias!($enum_ty);
                 #[warn(unreachable_patterns)]
                 match value {
                     $( __EnumTy::$enum_variant$(($($tuple)*))?$({$($struct)*})? => $value ),+
@@ -56,9 +53,9 @@ macro_rules! __impl_from_enum {
         }
     };
 
-    { $enum_ty:ty, $into:ty $(,)? } => {
+    { [$inline:meta] $enum_ty:ty, $into:ty $(,)? } => {
         impl ::core::convert::From<$enum_ty> for $into {
-            #[inline]
+            #[$inline]
             fn from(value: $enum_ty) -> Self {
                 match value {}
             }
@@ -70,25 +67,43 @@ macro_rules! __impl_from_enum {
 #[macro_export]
 macro_rules! __impl_enum_from {
     {
+        [$inline:meta]
         $enum_ty:ty,
         $from:ty,
-        $($enum_variant:ident$(($($tuple:tt)*))?$({$($struct:tt)*})? <=> $value:pat),+
+        $(
+            $enum_variant:ident$(($($tuple:tt)*))?$({$($struct:tt)*})?
+            <=> $value:pat $(=> $pattern:pat)?
+        ),+
         $(,)?
     } => {
+        #[doc = "Maps values into variants of the enum:"]
+        #[doc = ""]
+        $(
+            #[doc = ::core::concat!(
+                "- `", $crate::__arm_pattern_str!($value $(=> $pattern)?),
+                "` => `", ::core::stringify!($enum_variant),
+                $( "(", ::core::stringify!($($tuple)*), ")", )?
+                $( " { ", ::core::stringify!($($struct)*), " }", )?
+                "`",
+            )]
+        )+
         impl ::core::convert::From<$from> for $enum_ty {
-            #[inline]
+            #[$inline]
             fn from(value: $from) -> Self {
                 #[warn(unreachable_patterns)]
                 match value {
-                    $( $value => Self::$enum_variant$(($($tuple)*))?$({$($struct)*})? ),+
+                    $(
+                        $crate::__arm_pattern!($value $(=> $pattern)?)
+                            => Self::$enum_variant$(($($tuple)*))?$({$($struct)*})?
+                    ),+
                 }
             }
         }
     };
 
-    { $enum_ty:ty, $from:ty $(,)? } => {
+    { [$inline:meta] $enum_ty:ty, $from:ty $(,)? } => {
         impl ::core::convert::From<$from> for $enum_ty {
-            #[inline]
+            #[$inline]
             fn from(value: $from) -> Self {
                 match value {}
             }
@@ -100,36 +115,266 @@ macro_rules! __impl_enum_from {
 #[macro_export]
 macro_rules! __impl_enum_try_from {
     {
+        [$inline:meta]
+        [$error_ty:ty = $error:expr]
         $enum_ty:ty,
         $try_from:ty,
-        $($enum_variant:ident$(($($tuple:tt)*))?$({$($struct:tt)*})? <=> $value:pat),+
+        $(
+            $enum_variant:ident$(($($tuple:tt)*))?$({$($struct:tt)*})?
+            <=> $value:pat $(=> $pattern:pat)?
+        ),+
         $(,)?
     } => {
+        #[doc = "Maps values into variants of the enum, and fails on any other value:"]
+        #[doc = ""]
+        $(
+            #[doc = ::core::concat!(
+                "- `", $crate::__arm_pattern_str!($value $(=> $pattern)?),
+                "` => `", ::core::stringify!($enum_variant),
+                $( "(", ::core::stringify!($($tuple)*), ")", )?
+                $( " { ", ::core::stringify!($($struct)*), " }", )?
+                "`",
+            )]
+        )+
         impl ::core::convert::TryFrom<$try_from> for $enum_ty {
-            type Error = ();
+            type Error = $error_ty;
 
-            #[inline]
-            fn try_from(value: $try_from) -> Result<Self, Self::Error> {
+            #[$inline]
+            fn try_from(value: $try_from) -> Result<Self, $error_ty> {
                 #![allow(clippy::allow_attributes)]
                 #[warn(unreachable_patterns)]
                 Ok(match value {
-                    $( $value => Self::$enum_variant$(($($tuple)*))?$({$($struct)*})? ),+,
+                    $(
+                        $crate::__arm_pattern!($value $(=> $pattern)?)
+                            => Self::$enum_variant$(($($tuple)*))?$({$($struct)*})?
+                    ),+,
                     #[allow(clippy::wildcard_enum_match_arm)]
                     #[allow(unreachable_patterns)]
-                    _ => return Err(()),
+                    _ => return Err($error),
                 })
             }
         }
     };
 
-    { $enum_ty:ty, $try_from:ty $(,)? } => {
+    { [$inline:meta] [$error_ty:ty = $error:expr] $enum_ty:ty, $try_from:ty $(,)? } => {
         impl ::core::convert::TryFrom<$try_from> for $enum_ty {
-            type Error = ();
+            type Error = $error_ty;
 
-            #[inline]
-            fn try_from(_value: $try_from) -> Result<Self, Self::Error> {
-                Err(())
+            #[$inline]
+            fn try_from(_value: $try_from) -> Result<Self, $error_ty> {
+                Err($error)
             }
         }
     };
 }
+
+/// Choose the pattern matched by an arm in the reverse direction: the pattern given after `=>`
+/// if there is one, and otherwise the value of the arm.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __arm_pattern {
+    { $value:pat } => { $value };
+    { $value:pat => $pattern:pat } => { $pattern };
+}
+
+/// Stringify the pattern chosen by `__arm_pattern`, for generated documentation.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __arm_pattern_str {
+    { $value:pat } => { ::core::stringify!($value) };
+    { $value:pat => $pattern:pat } => { ::core::stringify!($pattern) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __count_variants {
+    { $($enum_variant:ident),* $(,)? } => {
+        <[&str]>::len(&[$(::core::stringify!($enum_variant)),*])
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_tables_from_enum {
+    {
+        [$inline:meta]
+        $enum_ty:ty,
+        $into:ty,
+        $($enum_variant:ident <=> $value:expr),+
+        $(,)?
+    } => {
+        #[doc = "Maps each variant of the enum into a value:"]
+        #[doc = ""]
+        $(
+            #[doc = ::core::concat!(
+                "- `", ::core::stringify!($enum_variant),
+                "` => `", ::core::stringify!($value), "`",
+            )]
+        )+
+        impl ::core::convert::From<$enum_ty> for $into {
+            #[$inline]
+            fn from(value: $enum_ty) -> Self {
+                const NAMES: &[&str] = &[$( ::core::stringify!($enum_variant) ),+];
+                const FORWARD: [$into; $crate::__count_variants!($($enum_variant),+)] = [
+                    $( $value ),+
+                ];
+
+                $crate::__enum_ty_alias!($enum_ty);
+                #[warn(unreachable_patterns)]
+                let index = match value {
+                    $(
+                        __EnumTy::$enum_variant => {
+                            // Locating the arm by name avoids declaring an item per variant,
+                            // which would make a repeated variant a name collision.
+                            const INDEX: usize = $crate::__private::arm_index(
+                                NAMES,
+                                ::core::stringify!($enum_variant),
+                            );
+                            INDEX
+                        }
+                    ),+
+                };
+                FORWARD[index]
+            }
+        }
+    };
+
+    { [$inline:meta] $enum_ty:ty, $into:ty $(,)? } => {
+        $crate::__impl_from_enum! { [$inline] $enum_ty, $into }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_tables_enum_from {
+    {
+        [$inline:meta]
+        $enum_ty:ty,
+        $from:ty,
+        $($enum_variant:ident <=> $value:expr),+
+        $(,)?
+    } => {
+        const _: () = {
+            const REVERSE: [($from, $enum_ty); $crate::__count_variants!($($enum_variant),+)] = [
+                $( ($value, <$enum_ty>::$enum_variant) ),+
+            ];
+
+            #[doc = "Maps values into variants of the enum:"]
+            #[doc = ""]
+            $(
+                #[doc = ::core::concat!(
+                    "- `", ::core::stringify!($value),
+                    "` => `", ::core::stringify!($enum_variant), "`",
+                )]
+            )+
+            impl ::core::convert::From<$from> for $enum_ty {
+                #[$inline]
+                fn from(value: $from) -> Self {
+                    for (entry, variant) in REVERSE {
+                        if entry == value {
+                            return variant;
+                        }
+                    }
+                    ::core::unreachable!(
+                        "the patterns of a bijective map are checked to be exhaustive",
+                    )
+                }
+            }
+        };
+    };
+
+    { [$inline:meta] $enum_ty:ty, $from:ty $(,)? } => {
+        $crate::__impl_enum_from! { [$inline] $enum_ty, $from }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_tables_enum_try_from {
+    {
+        [$inline:meta]
+        [$error_ty:ty = $error:expr]
+        $enum_ty:ty,
+        $try_from:ty,
+        $($enum_variant:ident <=> $value:expr),+
+        $(,)?
+    } => {
+        const _: () = {
+            const REVERSE: [($try_from, $enum_ty); $crate::__count_variants!($($enum_variant),+)] =
+                [$( ($value, <$enum_ty>::$enum_variant) ),+];
+
+            #[doc = "Maps values into variants of the enum, and fails on any other value:"]
+            #[doc = ""]
+            $(
+                #[doc = ::core::concat!(
+                    "- `", ::core::stringify!($value),
+                    "` => `", ::core::stringify!($enum_variant), "`",
+                )]
+            )+
+            impl ::core::convert::TryFrom<$try_from> for $enum_ty {
+                type Error = $error_ty;
+
+                #[$inline]
+                fn try_from(value: $try_from) -> Result<Self, $error_ty> {
+                    for (entry, variant) in REVERSE {
+                        if entry == value {
+                            return Ok(variant);
+                        }
+                    }
+                    Err($error)
+                }
+            }
+        };
+    };
+
+    { [$inline:meta] [$error_ty:ty = $error:expr] $enum_ty:ty, $try_from:ty $(,)? } => {
+        $crate::__impl_enum_try_from! { [$inline] [$error_ty = $error] $enum_ty, $try_from }
+    };
+}
+
+/// Match on the values of a map in an unused function, in order to check that the patterns are
+/// exhaustive (for `exhaustive`) and trigger `#[warn(unreachable_patterns)]` on duplicates
+/// (for either `exhaustive` or `nonexhaustive`). This is used for modes which do not otherwise
+/// match on the values.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __check_patterns {
+    {
+        exhaustive
+        $ty:ty,
+        $($enum_variant:ident$(($($tuple:tt)*))?$({$($struct:tt)*})? <=> $value:pat),+
+        $(,)?
+    } => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn check_patterns(value: $ty) {
+                #[warn(unreachable_patterns)]
+                match value {
+                    $( $value => {} ),+
+                }
+            }
+        };
+    };
+
+    {
+        nonexhaustive
+        $ty:ty,
+        $($enum_variant:ident$(($($tuple:tt)*))?$({$($struct:tt)*})? <=> $value:pat),+
+        $(,)?
+    } => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn check_patterns(value: $ty) {
+                #![allow(clippy::allow_attributes)]
+                #[warn(unreachable_patterns)]
+                match value {
+                    $( $value => {} ),+,
+                    #[allow(unreachable_patterns)]
+                    _ => {}
+                }
+            }
+        };
+    };
+
+    { $exhaustiveness:ident $ty:ty $(,)? } => {};
+}
@@ -0,0 +1,447 @@
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut};
+
+
+/// An enum whose variants are in bijection with the indices `0..COUNT`.
+///
+/// This trait is usually implemented with [`enum_indexed`], and is used by [`EnumArray`] to
+/// index an array with enum variants.
+///
+/// [`enum_indexed`]: crate::enum_indexed
+pub trait EnumIndex: Sized {
+    /// The number of variants of the enum, which is also the number of valid indices.
+    const COUNT: usize;
+
+    /// Map the enum into its index, which should be less than `Self::COUNT`.
+    fn to_index(self) -> usize;
+
+    /// Map an index into an enum variant, returning `None` if the index is not less than
+    /// `Self::COUNT`.
+    fn from_index(index: usize) -> Option<Self>;
+}
+
+/// An array with one element per variant of an enum, which can be indexed by that enum.
+///
+/// The enum type `E` should implement [`EnumIndex`] (usually via [`enum_indexed`]), and `N` must
+/// be equal to `E::COUNT`; using a constructor with any other `N` is a compile-time error. Note
+/// that `<E as EnumIndex>::COUNT` may be used as `N` when `E` is a concrete type.
+///
+/// # Example
+/// ```
+/// use bijective_enum_map::{enum_indexed, EnumArray, EnumIndex};
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum Channel {
+///     Red,
+///     Green,
+///     Blue,
+/// }
+///
+/// enum_indexed! {
+///     Channel,
+///     Red   <=> 0,
+///     Green <=> 1,
+///     Blue  <=> 2,
+/// }
+///
+/// type PerChannel<T> = EnumArray<Channel, T, { <Channel as EnumIndex>::COUNT }>;
+///
+/// let mut gains = PerChannel::each_variant(|channel| match channel {
+///     Channel::Red  => 1.5,
+///     _             => 1.0,
+/// });
+/// gains[Channel::Blue] = 0.5;
+///
+/// assert_eq!(gains[Channel::Red], 1.5);
+/// assert_eq!(gains[Channel::Green], 1.0);
+/// assert_eq!(gains.into_array(), [1.5, 1.0, 0.5]);
+/// ```
+///
+/// [`enum_indexed`]: crate::enum_indexed
+pub struct EnumArray<E, T, const N: usize> {
+    array: [T; N],
+    _enum: PhantomData<fn(E) -> E>,
+}
+
+impl<E: EnumIndex, T, const N: usize> EnumArray<E, T, N> {
+    /// Create an array whose element for each variant is computed by `f`.
+    ///
+    /// `f` is called on each variant in order of their indices.
+    ///
+    /// # Panics
+    /// Panics if `E::from_index` fails for some index less than `N` (which can only happen if the
+    /// `EnumIndex` implementation is incorrect).
+    pub fn each_variant<F: FnMut(E) -> T>(mut f: F) -> Self {
+        let () = Self::LEN_IS_COUNT;
+
+        let mut index = 0;
+        let array = [(); N].map(|()| {
+            let variant = E::from_index(index)
+                .expect("`EnumIndex::from_index` should succeed for every index below `COUNT`");
+            index += 1;
+            f(variant)
+        });
+
+        Self {
+            array,
+            _enum: PhantomData,
+        }
+    }
+
+    /// Wrap an array whose element at each index corresponds to the variant with that index.
+    #[inline]
+    pub fn from_array(array: [T; N]) -> Self {
+        let () = Self::LEN_IS_COUNT;
+
+        Self {
+            array,
+            _enum: PhantomData,
+        }
+    }
+
+    /// The length of an `EnumArray` must equal the number of variants of its enum.
+    ///
+    /// Evaluating this constant indexes out of bounds when `N != E::COUNT`, which is a
+    /// compile-time error wherever a constructor is used. (Unlike `assert!`, indexing is
+    /// permitted in constants on every supported Rust version.)
+    const LEN_IS_COUNT: () = [()][(N != E::COUNT) as usize];
+}
+
+impl<E, T, const N: usize> EnumArray<E, T, N> {
+    /// Unwrap the underlying array, whose elements are in order of their variants' indices.
+    #[inline]
+    pub fn into_array(self) -> [T; N] {
+        self.array
+    }
+
+    /// Get a reference to the underlying array.
+    #[inline]
+    pub fn as_array(&self) -> &[T; N] {
+        &self.array
+    }
+
+    /// Get a mutable reference to the underlying array.
+    #[inline]
+    pub fn as_mut_array(&mut self) -> &mut [T; N] {
+        &mut self.array
+    }
+}
+
+impl<E: EnumIndex, T, const N: usize> Index<E> for EnumArray<E, T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, variant: E) -> &Self::Output {
+        &self.array[variant.to_index()]
+    }
+}
+
+impl<E: EnumIndex, T, const N: usize> IndexMut<E> for EnumArray<E, T, N> {
+    #[inline]
+    fn index_mut(&mut self, variant: E) -> &mut Self::Output {
+        &mut self.array[variant.to_index()]
+    }
+}
+
+impl<E: EnumIndex, T: Default, const N: usize> Default for EnumArray<E, T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::each_variant(|_| T::default())
+    }
+}
+
+// The below implementations are written by hand to avoid placing bounds on `E`.
+
+impl<E, T: Debug, const N: usize> Debug for EnumArray<E, T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("EnumArray").field(&self.array).finish()
+    }
+}
+
+impl<E, T: Clone, const N: usize> Clone for EnumArray<E, T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            array: self.array.clone(),
+            _enum: PhantomData,
+        }
+    }
+}
+
+impl<E, T: Copy, const N: usize> Copy for EnumArray<E, T, N> {}
+
+impl<E, T: PartialEq, const N: usize> PartialEq for EnumArray<E, T, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.array == other.array
+    }
+}
+
+impl<E, T: Eq, const N: usize> Eq for EnumArray<E, T, N> {}
+
+impl<E, T: Hash, const N: usize> Hash for EnumArray<E, T, N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.array.hash(state);
+    }
+}
+
+/// Implement [`EnumIndex`] for an enum, mapping its variants bijectively onto `0..COUNT`.
+///
+/// The enum type must be specified, followed by an arm for each variant mapping the variant to
+/// its index. Only unit variants are supported, and each index must be an integer literal.
+/// `COUNT` is the number of arms.
+///
+/// The indices must be precisely `0..COUNT`, in any order. An index which is not less than
+/// `COUNT`, or a repeated index, is a compile-time error, while a repeated variant *should*
+/// trigger `#[warn(unreachable_patterns)]`, as with [`bijective_enum_map`].
+///
+/// Implementing [`EnumIndex`] allows the enum to index an [`EnumArray`].
+///
+/// # Example
+/// ```
+/// use bijective_enum_map::{enum_indexed, EnumIndex};
+/// #[derive(Debug, PartialEq, Eq)]
+/// enum Suit {
+///     Clubs,
+///     Diamonds,
+///     Hearts,
+///     Spades,
+/// }
+///
+/// enum_indexed! {
+///     Suit,
+///     Clubs    <=> 0,
+///     Diamonds <=> 1,
+///     Hearts   <=> 2,
+///     Spades   <=> 3,
+/// }
+///
+/// assert_eq!(Suit::COUNT, 4);
+/// assert_eq!(Suit::Hearts.to_index(), 2);
+/// assert_eq!(Suit::from_index(1), Some(Suit::Diamonds));
+/// assert_eq!(Suit::from_index(4), None);
+/// ```
+///
+/// [`bijective_enum_map`]: crate::bijective_enum_map
+/// [`EnumArray`]: crate::EnumArray
+/// [`EnumIndex`]: crate::EnumIndex
+#[macro_export]
+macro_rules! enum_indexed {
+    {
+        $enum_ty:ty,
+        $($enum_variant:ident <=> $index:literal),+
+        $(,)?
+    } => {
+        impl $crate::EnumIndex for $enum_ty {
+            const COUNT: usize = $crate::__count_variants!($($enum_variant),+);
+
+            #[inline]
+            fn to_index(self) -> usize {
+                #[warn(unreachable_patterns)]
+                match self {
+                    $( Self::$enum_variant => $index ),+
+                }
+            }
+
+            #[inline]
+            fn from_index(index: usize) -> ::core::option::Option<Self> {
+                #![allow(clippy::allow_attributes)]
+                #[warn(unreachable_patterns)]
+                ::core::option::Option::Some(match index {
+                    $( $index => Self::$enum_variant ),+,
+                    #[allow(unreachable_patterns)]
+                    _ => return ::core::option::Option::None,
+                })
+            }
+        }
+
+        // Indexing out of bounds is a const evaluation error, so this checks that the indices are
+        // exactly `0..COUNT`.
+        const _: () = [()][
+            !$crate::__private::is_ordinal_range(&[$( $index as u128 ),+], false) as usize
+        ];
+    };
+
+    { $enum_ty:ty $(,)? } => {
+        impl $crate::EnumIndex for $enum_ty {
+            const COUNT: usize = 0;
+
+            #[inline]
+            fn to_index(self) -> usize {
+                match self {}
+            }
+
+            #[inline]
+            fn from_index(_index: usize) -> ::core::option::Option<Self> {
+                ::core::option::Option::None
+            }
+        }
+    };
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{EnumArray, EnumIndex};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Channel {
+        Red,
+        Green,
+        Blue,
+    }
+
+    enum_indexed! {
+        Channel,
+        Red   <=> 0,
+        Green <=> 1,
+        Blue  <=> 2,
+    }
+
+    type PerChannel<T> = EnumArray<Channel, T, { <Channel as EnumIndex>::COUNT }>;
+
+    #[test]
+    fn empty() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Empty {}
+
+        enum_indexed! { Empty }
+
+        assert_eq!(Empty::COUNT, 0);
+        assert_eq!(Empty::from_index(0), None);
+
+        let array = EnumArray::<Empty, u8, 0>::each_variant(|_| 1);
+        assert_eq!(array.into_array(), [0_u8; 0]);
+    }
+
+    #[test]
+    fn out_of_order() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Backwards {
+            Two,
+            One,
+            Zero,
+        }
+
+        enum_indexed! {
+            Backwards,
+            Zero <=> 0,
+            Two  <=> 2,
+            One  <=> 1,
+        }
+
+        assert_eq!(Backwards::COUNT, 3);
+        assert_eq!(Backwards::Two.to_index(), 2);
+        assert_eq!(Backwards::from_index(0), Some(Backwards::Zero));
+        assert_eq!(Backwards::from_index(3), None);
+    }
+
+    #[test]
+    fn each_variant() {
+        let names = PerChannel::each_variant(|channel| match channel {
+            Channel::Red   => "red",
+            Channel::Green => "green",
+            Channel::Blue  => "blue",
+        });
+
+        assert_eq!(names[Channel::Green], "green");
+        assert_eq!(names.into_array(), ["red", "green", "blue"]);
+    }
+
+    #[test]
+    fn index_mut() {
+        let mut counts = PerChannel::<u32>::default();
+        counts[Channel::Blue] += 2;
+        counts[Channel::Red] += 1;
+        counts[Channel::Blue] += 3;
+
+        assert_eq!(counts, PerChannel::from_array([1, 0, 5]));
+    }
+}
+
+#[cfg(doctest)]
+pub mod compile_fail_tests {
+    /// ```compile_fail,E0080
+    /// use bijective_enum_map::enum_indexed;
+    /// enum AtMostTwo {
+    ///     Zero,
+    ///     One,
+    ///     Two,
+    /// }
+    ///
+    /// enum_indexed! {
+    ///     AtMostTwo,
+    ///     Zero <=> 0,
+    ///     One  <=> 1,
+    ///     Two  <=> 3,
+    /// }
+    /// ```
+    pub fn _index_too_large() {}
+
+    /// ```compile_fail,E0080
+    /// use bijective_enum_map::enum_indexed;
+    /// enum AtMostTwo {
+    ///     Zero,
+    ///     One,
+    ///     Two,
+    /// }
+    ///
+    /// enum_indexed! {
+    ///     AtMostTwo,
+    ///     Zero <=> 0,
+    ///     One  <=> 1,
+    ///     Two  <=> 1,
+    /// }
+    /// ```
+    pub fn _repeated_index() {}
+
+    /// ```compile_fail,E0004
+    /// use bijective_enum_map::enum_indexed;
+    /// enum AtMostTwo {
+    ///     Zero,
+    ///     One,
+    ///     Two,
+    /// }
+    ///
+    /// enum_indexed! {
+    ///     AtMostTwo,
+    ///     Zero <=> 0,
+    ///     One  <=> 1,
+    /// }
+    /// ```
+    pub fn _missing_variant() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::enum_indexed;
+    /// enum Nonunit {
+    ///     Something(u8),
+    /// }
+    ///
+    /// enum_indexed! {
+    ///     Nonunit,
+    ///     Something(_) <=> 0,
+    /// }
+    /// ```
+    pub fn _non_unit_variant() {}
+
+    /// ```compile_fail,E0080
+    /// use bijective_enum_map::{enum_indexed, EnumArray};
+    /// enum Channel {
+    ///     Red,
+    ///     Green,
+    ///     Blue,
+    /// }
+    ///
+    /// enum_indexed! {
+    ///     Channel,
+    ///     Red   <=> 0,
+    ///     Green <=> 1,
+    ///     Blue  <=> 2,
+    /// }
+    ///
+    /// let _ = EnumArray::<Channel, u8, 2>::from_array([0, 1]);
+    /// ```
+    pub fn _wrong_length() {}
+}
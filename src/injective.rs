@@ -13,6 +13,11 @@
 /// from `&str`, for example. Unintended options like mapping into `Range<u8>` and from `u8`
 /// might be possible, but are not tested here. Note that non-unit variants are supported.
 ///
+/// An arm may instead be written as `Variant <=> value => pattern`, in which case `value` is the
+/// canonical value that the variant maps into, and the reverse conversion matches `pattern` (for
+/// instance, a range of values) rather than `value`. The value must still be a valid pattern.
+/// Such arms are not supported by `mode = tables` or `json_schema = true`.
+///
 /// This map is intended to be "injective"; different enum variants should map into different
 /// values, so that they can be mapped back unambiguously. The map may (or may not) also be
 /// "surjective", in which any possible value of the target type is associated with some enum
@@ -26,6 +31,91 @@
 /// listed will be mapped from the duplicate value. Such a warning should also occur if an enum
 /// variant is repeated.
 ///
+/// The generated implementations are documented with a list of the arms of the map in their
+/// direction, so that the mapping is visible in rustdoc without reading the source.
+///
+/// # Options
+///
+/// Options may be given after the types and before the arms, in the form `option = value`.
+///
+/// - `mode = match` (the default): implement the conversions with `match` statements.
+/// - `mode = tables`: store the values of the map in `const` tables, one indexed by the enum
+///   for the `From` conversion and one scanned by the `TryFrom` conversion. This can result in
+///   smaller code for large maps (e.g., on embedded targets). Every variant must be a unit
+///   variant, every value must be a constant expression, `$into` must be `Copy`, and `$try_from`
+///   must be `PartialEq`. Duplicate values are still detected by the same warning as in
+///   `mode = match`.
+/// - `mode = contiguous`: for a fieldless enum which maps each variant to its own discriminant,
+///   implement the `From` conversion with an `as` cast, and the `TryFrom` conversion with a range
+///   check and an index into a `const` table of the variants. The arms must be listed in order of
+///   consecutive values; this, and that each value equals the discriminant of its variant, is
+///   checked at compile time, which requires Rust 1.57. The discriminants and values are compared
+///   as `i128`, so a discriminant which does not fit in `$into` is rejected rather than truncated
+///   by the cast; declaring a `#[repr]` matching `$into` keeps the two in agreement. Every variant
+///   must be a unit variant, the enum must be `Copy`, and `$into` and `$try_from` must be
+///   primitive integer types.
+/// - `mode = cow`: map into `Cow<'static, str>`, written as the only type after the enum (e.g.
+///   `injective_enum_map! { Enum, Cow<'static, str>, mode = cow, ... }`). The `From` conversion
+///   returns `Cow::Borrowed` of each value, and `TryFrom<&str>` and `TryFrom<Cow<'_, str>>` are
+///   implemented for the enum by matching against the same values, so every value must be a
+///   string literal (or other valid pattern). Requires the `alloc` feature.
+/// - `inline = default` (the default), `inline = never`, or `inline = always`: emit `#[inline]`,
+///   `#[inline(never)]`, or `#[inline(always)]` (respectively) on the generated conversion
+///   functions and on the inherent functions generated by other options. For instance, `never`
+///   can avoid code bloat from a large map which is used in many places, while `always` may help
+///   a small map used in a hot loop.
+/// - `vis = pub` (the default): the visibility of any inherent items (associated functions or
+///   constants) generated for the enum, such as `pub(crate)` or `pub(self)` (which is private).
+///   Trait implementations are not affected.
+/// - `error: Type = expression`: use `Type` as the `Error` type of the `TryFrom` conversion, and
+///   return `Err(expression)` when no value matches, instead of `Err(())`. The expression is
+///   evaluated each time a conversion fails.
+/// - `error: Type::Variant`: shorthand for `error: Type = Type::Variant`, where `Type` is the path
+///   without its last segment. A unit struct at a path must therefore use the full form.
+/// - `error: UnitStruct`: shorthand for `error: UnitStruct = UnitStruct`, such as
+///   `error: NoMatchingVariant` with the crate's [`NoMatchingVariant`] error.
+/// - `iter = true`: generate an inherent `iter()` function on the enum, returning an iterator
+///   over every variant in the order of the arms (backed by an array). Every variant must be a
+///   unit variant, and only one map of the enum may enable this option. Defaults to `false`.
+/// - `ordinal = true`: generate inherent `next()`, `prev()`, `wrapping_next()`, and
+///   `checked_add(n)` functions on the enum, which navigate between variants by their values.
+///   Every variant must be a unit variant mapped to an integer constant, and the values must be
+///   exactly `0..N` (in any order), where `N` is the number of arms; this is checked at compile
+///   time, and requires Rust 1.57. The conversions must be into and from the same integer type,
+///   and only one map of the enum may enable this option. Defaults to `false`.
+/// - `expected_values = true`: generate an inherent `expected_values()` function on the enum,
+///   returning a `&'static [$into]` slice of the values of the map in the order of the arms
+///   (for instance, to list the accepted inputs in an error message), or `&'static [&'static str]`
+///   with `mode = cow`. Every value must be a constant expression; for an arm written as
+///   `Variant <=> value => pattern`, only `value` is listed, even though the reverse conversion
+///   accepts every value matching `pattern`. Only one map of the enum may enable this option.
+///   Defaults to `false`.
+/// - `bytes = le` or `bytes = be`: generate inherent `try_read_from(bytes)` and `write_to(buf)`
+///   functions on the enum, which read a `$try_from` from the start of a byte slice and convert it
+///   into the enum, or write the `$into` value of the enum to the start of a buffer, in
+///   little-endian or big-endian byte order. Both return the rest of the slice, and fail with
+///   [`ReadError`] or [`BufferTooShort`]. `$try_from` and `$into` must be primitive integer types,
+///   and the enum must implement `Clone`, as `write_to` takes `&self` and converts a clone.
+///   Only one map of the enum may enable this option. Defaults to `false`.
+/// - `proptest = true`: generate an inherent `any_variant()` function on the enum, returning a
+///   `proptest` strategy which generates every variant in the map, with the fields of non-unit
+///   variants generated by their `Arbitrary` implementations (so the fields of each arm must be
+///   bound to identifiers, as in `Variant(a, b)` or `Variant { a, b }`). If every variant is a
+///   unit variant, `Arbitrary` is also implemented for the enum. Strategies for the fields of
+///   some variants can be given with `proptest = { Variant(strategy, ...), ... }`, with one
+///   strategy per field in the order the arm binds them (for instance, `any::<T>()` to keep a
+///   field arbitrary); the other variants use `Arbitrary`, as with `proptest = true`. The enum
+///   must implement `Debug`, and only one map of the enum may enable this option. Requires the
+///   `proptest` feature. Defaults to `false`.
+/// - `json_schema = true`: implement `schemars::JsonSchema` for the enum, with a schema listing
+///   the values of the map as the permitted values, and with the JSON type of `$into`. The values
+///   must implement `serde::Serialize`, and `$into` must implement `schemars::JsonSchema`. Arms
+///   written as `Variant <=> value => pattern` are rejected, since the schema could not list the
+///   values matched by `pattern`. This requires the `schemars` feature. Defaults to `false`.
+/// - `const_assert = true`: check at compile time that the values are distinct, exactly as
+///   [`assert_injective`] does. Every variant must be a unit variant, and every value must be a
+///   constant of a supported type; this requires Rust 1.57. Defaults to `false`.
+///
 /// # Examples
 ///
 /// ## Map into and from two other types:
@@ -110,6 +200,80 @@
 /// assert_eq!(Version::try_from(5_u8), Err(()));
 /// ```
 ///
+/// ## Map a range of values into one variant:
+/// ```
+/// use bijective_enum_map::injective_enum_map;
+/// #[derive(Debug, PartialEq, Eq)]
+/// enum Opcode {
+///     Nop,
+///     Halt,
+///     Experimental,
+/// }
+///
+/// injective_enum_map! {
+///     Opcode, u8,
+///     Nop          <=> 0x00,
+///     Halt         <=> 0x01,
+///     Experimental <=> 0xF0 => 0xF0..=0xFF,
+/// }
+///
+/// assert_eq!(u8::from(Opcode::Experimental), 0xF0);
+/// assert_eq!(Opcode::try_from(0xF0_u8), Ok(Opcode::Experimental));
+/// assert_eq!(Opcode::try_from(0xF7_u8), Ok(Opcode::Experimental));
+/// assert_eq!(Opcode::try_from(0x02_u8), Err(()));
+/// ```
+///
+/// ## Return a custom error:
+/// ```
+/// use bijective_enum_map::injective_enum_map;
+/// #[derive(Debug, PartialEq, Eq)]
+/// enum Opcode {
+///     Nop,
+///     Jump,
+///     Halt,
+/// }
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// enum ProtocolError {
+///     UnknownOpcode,
+///     // ...
+/// }
+///
+/// injective_enum_map! {
+///     Opcode, u8,
+///     error: ProtocolError::UnknownOpcode,
+///     Nop  <=> 0x00,
+///     Jump <=> 0x10,
+///     Halt <=> 0xFF,
+/// }
+///
+/// assert_eq!(Opcode::try_from(0x10_u8), Ok(Opcode::Jump));
+/// assert_eq!(Opcode::try_from(0x20_u8), Err(ProtocolError::UnknownOpcode));
+/// ```
+///
+/// ## Store the map in `const` tables:
+/// ```
+/// use bijective_enum_map::injective_enum_map;
+/// #[derive(Debug, PartialEq, Eq)]
+/// enum Command {
+///     Reset,
+///     Read,
+///     Write,
+/// }
+///
+/// injective_enum_map! {
+///     Command, &'static str, &str,
+///     mode = tables,
+///     Reset <=> "reset",
+///     Read  <=> "read",
+///     Write <=> "write",
+/// }
+///
+/// assert_eq!(<&str>::from(Command::Read), "read");
+/// assert_eq!(Command::try_from("write"), Ok(Command::Write));
+/// assert_eq!(Command::try_from("erase"), Err(()));
+/// ```
+///
 /// ## Map into and from another enum:
 /// ```
 /// use bijective_enum_map::injective_enum_map;
@@ -140,34 +304,33 @@
 /// // You could use `bijective_enum_map` instead.
 /// assert_eq!(Enum::try_from(Other::Uno), Ok(Enum::One));
 /// ```
+///
+/// [`assert_injective`]: crate::assert_injective
+/// [`NoMatchingVariant`]: crate::NoMatchingVariant
+/// [`ReadError`]: crate::ReadError
+/// [`BufferTooShort`]: crate::BufferTooShort
 #[macro_export]
 macro_rules! injective_enum_map {
     { $enum_ty:ty, $into:ty, $try_from:ty, $($body:tt)* } => {
-        $crate::__impl_from_enum! { $enum_ty, $into, $($body)* }
-        $crate::__impl_enum_try_from! { $enum_ty, $try_from, $($body)* }
+        $crate::__enum_map_options! { @start injective [$enum_ty, $into, $try_from] $($body)* }
     };
 
     { $enum_ty:ty, $into:ty, $try_from:ty } => {
-        $crate::__impl_from_enum! { $enum_ty, $into }
-        $crate::__impl_enum_try_from! { $enum_ty, $try_from }
+        $crate::__enum_map_options! { @start injective [$enum_ty, $into, $try_from] }
     };
 
     { $enum_ty:ty, $both:ty, $($body:tt)* } => {
-        $crate::__impl_from_enum! { $enum_ty, $both, $($body)* }
-        $crate::__impl_enum_try_from! { $enum_ty, $both, $($body)* }
+        $crate::__enum_map_options! { @start injective [$enum_ty, $both] $($body)* }
     };
 
     { $enum_ty:ty, $both:ty } => {
-        $crate::__impl_from_enum! { $enum_ty, $both }
-        $crate::__impl_enum_try_from! { $enum_ty, $both }
+        $crate::__enum_map_options! { @start injective [$enum_ty, $both] }
     };
 }
 
 
 #[cfg(test)]
 mod tests {
-    use crate::injective_enum_map;
-
     #[test]
     fn empty_both_specified() {
         #[derive(Debug, PartialEq, Eq)]
@@ -381,6 +544,203 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tables_mode() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Empty {}
+
+        #[derive(Debug, PartialEq, Eq)]
+        enum AtMostTwo {
+            Zero,
+            One,
+            Two,
+        }
+
+        injective_enum_map! { Empty, u8, mode = tables }
+        injective_enum_map! {
+            AtMostTwo, &'static str, &str,
+            mode = tables,
+            Zero <=> "zero",
+            One  <=> "one",
+            Two  <=> "two",
+        }
+        injective_enum_map! {
+            AtMostTwo, u8, u32,
+            mode = tables,
+            Two  <=> 2,
+            One  <=> 1,
+            Zero <=> 0,
+        }
+
+        assert_eq!(Empty::try_from(0_u8), Err(()));
+        assert_eq!(<&str>::from(AtMostTwo::Zero), "zero");
+        assert_eq!(<&str>::from(AtMostTwo::Two), "two");
+        assert_eq!(AtMostTwo::try_from("one"), Ok(AtMostTwo::One));
+        assert_eq!(AtMostTwo::try_from("three"), Err(()));
+        assert_eq!(u8::from(AtMostTwo::One), 1);
+        assert_eq!(AtMostTwo::try_from(2_u32), Ok(AtMostTwo::Two));
+        assert_eq!(AtMostTwo::try_from(3_u32), Err(()));
+    }
+
+    #[test]
+    fn inline_option() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum AtMostTwo {
+            Zero,
+            One,
+            Two,
+        }
+
+        injective_enum_map! {
+            AtMostTwo, &'static str, &str,
+            inline = never,
+            Zero <=> "zero",
+            One  <=> "one",
+            Two  <=> "two",
+        }
+        injective_enum_map! {
+            AtMostTwo, u8,
+            mode = tables,
+            inline = always,
+            iter = true,
+            expected_values = true,
+            Zero <=> 0,
+            One  <=> 1,
+            Two  <=> 2,
+        }
+        injective_enum_map! {
+            AtMostTwo, char,
+            inline = default,
+            Zero <=> '0',
+            One  <=> '1',
+            Two  <=> '2',
+        }
+
+        assert_eq!(<&str>::from(AtMostTwo::One), "one");
+        assert_eq!(AtMostTwo::try_from("two"), Ok(AtMostTwo::Two));
+        assert_eq!(u8::from(AtMostTwo::Two), 2);
+        assert_eq!(AtMostTwo::try_from(3_u8), Err(()));
+        assert_eq!(AtMostTwo::try_from('0'), Ok(AtMostTwo::Zero));
+        // The inherent functions of the other options are also marked `#[inline(always)]`.
+        assert_eq!(AtMostTwo::iter().last(), Some(AtMostTwo::Two));
+        assert_eq!(AtMostTwo::expected_values(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn pattern_arms() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Opcode {
+            Nop,
+            Reserved(u16),
+            Experimental,
+        }
+
+        injective_enum_map! {
+            Opcode, u16,
+            Nop          <=> 0x0000,
+            Reserved(n)  <=> n => n @ 0xE000..=0xEFFF,
+            Experimental <=> 0xF000 => 0xF000..=0xFFFF | 0x0001,
+        }
+
+        assert_eq!(u16::from(Opcode::Experimental), 0xF000);
+        assert_eq!(Opcode::try_from(0xF123_u16), Ok(Opcode::Experimental));
+        assert_eq!(Opcode::try_from(0x0001_u16), Ok(Opcode::Experimental));
+        assert_eq!(u16::from(Opcode::Reserved(0xE123)), 0xE123);
+        assert_eq!(Opcode::try_from(0xE123_u16), Ok(Opcode::Reserved(0xE123)));
+        assert_eq!(Opcode::try_from(0x0002_u16), Err(()));
+    }
+
+    #[test]
+    fn error_option() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Empty {}
+
+        #[derive(Debug, PartialEq, Eq)]
+        enum AtMostTwo {
+            Zero,
+            One,
+            Two,
+        }
+
+        #[derive(Debug, PartialEq, Eq)]
+        enum ParseError {
+            Unknown,
+        }
+
+        mod errors {
+            #[derive(Debug, PartialEq, Eq)]
+            pub enum NestedError {
+                Unknown,
+            }
+        }
+
+        injective_enum_map! {
+            Empty, u8,
+            error: ParseError = ParseError::Unknown,
+        }
+        injective_enum_map! {
+            AtMostTwo, &'static str, &str,
+            error: ParseError::Unknown,
+            Zero <=> "zero",
+            One  <=> "one",
+            Two  <=> "two",
+        }
+        injective_enum_map! {
+            AtMostTwo, u8,
+            mode = tables,
+            error: &'static str = "not at most two",
+            Zero <=> 0,
+            One  <=> 1,
+            Two  <=> 2,
+        }
+
+        assert_eq!(Empty::try_from(0_u8), Err(ParseError::Unknown));
+        assert_eq!(AtMostTwo::try_from("one"), Ok(AtMostTwo::One));
+        assert_eq!(AtMostTwo::try_from("three"), Err(ParseError::Unknown));
+        assert_eq!(AtMostTwo::try_from(2_u8), Ok(AtMostTwo::Two));
+        injective_enum_map! {
+            AtMostTwo, char,
+            error: errors::NestedError::Unknown,
+            Zero <=> '0',
+            One  <=> '1',
+            Two  <=> '2',
+        }
+
+        assert_eq!(AtMostTwo::try_from(3_u8), Err("not at most two"));
+        assert_eq!(AtMostTwo::try_from('1'), Ok(AtMostTwo::One));
+        assert_eq!(AtMostTwo::try_from('3'), Err(errors::NestedError::Unknown));
+    }
+
+    #[test]
+    fn variants_named_like_options() {
+        #[allow(non_camel_case_types)]
+        #[derive(Debug, PartialEq, Eq)]
+        enum Level {
+            error,
+            warn,
+            mode,
+        }
+
+        injective_enum_map! {
+            Level, u8,
+            error <=> 0,
+            warn  <=> 1,
+            mode  <=> 2,
+        }
+        injective_enum_map! {
+            Level, &'static str, &str,
+            error: &'static str = "unknown level",
+            error <=> "error",
+            warn  <=> "warn",
+            mode  <=> "mode",
+        }
+
+        assert_eq!(u8::from(Level::error), 0);
+        assert_eq!(Level::try_from(2_u8), Ok(Level::mode));
+        assert_eq!(Level::try_from("error"), Ok(Level::error));
+        assert_eq!(Level::try_from("info"), Err("unknown level"));
+    }
+
     #[test]
     fn intentionally_non_injective() {
         #[derive(Debug, PartialEq, Eq)]
@@ -464,6 +824,29 @@ pub mod compile_fail_tests {
     /// ```
     pub fn _nonempty_not_injective_warning() {}
 
+    /// ```
+    /// use bijective_enum_map::injective_enum_map;
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// enum AtMostTwo {
+    ///     Zero,
+    ///     One,
+    ///     Two,
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     AtMostTwo, u8,
+    ///     mode = tables,
+    ///     Zero <=> 0,
+    ///     One  <=> 1,
+    ///     Two  <=> 2,
+    ///     Zero <=> 3,
+    /// }
+    ///
+    /// assert_eq!(u8::from(AtMostTwo::Zero), 0);
+    /// assert_eq!(AtMostTwo::try_from(3_u8), Ok(AtMostTwo::Zero));
+    /// ```
+    pub fn _tables_repeated_variant() {}
+
     /// ```compile_fail
     /// #![deny(warnings)]
     ///
@@ -553,6 +936,126 @@ pub mod compile_fail_tests {
     // /// ```
     // pub fn _enum_to_string_bad_lifetimes() {}
 
+    /// ```compile_fail
+    /// #![deny(warnings)]
+    ///
+    /// use bijective_enum_map::injective_enum_map;
+    /// enum AtMostTwo {
+    ///     Zero,
+    ///     One,
+    ///     Two,
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     AtMostTwo, u8,
+    ///     mode = tables,
+    ///     Zero <=> 0,
+    ///     One  <=> 1,
+    ///     Two  <=> 0,
+    /// }
+    /// ```
+    pub fn _tables_not_injective_warning() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::injective_enum_map;
+    /// enum Nonempty {
+    ///     Something(u8),
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     Nonempty, u8,
+    ///     mode = tables,
+    ///     Something(num) <=> num,
+    /// }
+    /// ```
+    pub fn _tables_non_unit_variant() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::injective_enum_map;
+    /// enum Nonempty {
+    ///     Something,
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     Nonempty, u8,
+    ///     mdoe = tables,
+    ///     Something <=> 0,
+    /// }
+    /// ```
+    pub fn _unknown_option() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::injective_enum_map;
+    /// enum Nonempty {
+    ///     Something,
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     Nonempty, u8,
+    ///     inline = sometimes,
+    ///     Something <=> 0,
+    /// }
+    /// ```
+    pub fn _unknown_inline() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::injective_enum_map;
+    /// enum Nonempty {
+    ///     Something,
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     Nonempty, u8,
+    ///     vis = private,
+    ///     Something <=> 0,
+    /// }
+    /// ```
+    pub fn _invalid_vis() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::injective_enum_map;
+    /// enum Nonempty {
+    ///     Something,
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     Nonempty, &'static str, &str,
+    ///     mode = cow,
+    ///     Something <=> "something",
+    /// }
+    /// ```
+    pub fn _cow_with_three_types() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::injective_enum_map;
+    /// enum Nonempty {
+    ///     Something,
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     Nonempty, u8,
+    ///     error = (),
+    ///     Something <=> 0,
+    /// }
+    /// ```
+    pub fn _malformed_error() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::injective_enum_map;
+    /// enum Opcode {
+    ///     Nop,
+    ///     Experimental,
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     Opcode, u8,
+    ///     mode = tables,
+    ///     Nop          <=> 0x00,
+    ///     Experimental <=> 0xF0 => 0xF0..=0xFF,
+    /// }
+    /// ```
+    pub fn _tables_pattern_arm() {}
+
     // Doesn't seem to have a compiler error number
     /// ```compile_fail
     /// use bijective_enum_map::injective_enum_map;
@@ -0,0 +1,88 @@
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_iter {
+    { false $($rest:tt)* } => {};
+
+    {
+        true [$vis:vis] [$inline:meta]
+        $enum_ty:ty,
+        $($enum_variant:ident <=> $value:expr $(=> $pattern:pat)?),*
+        $(,)?
+    } => {
+        impl $enum_ty {
+            #[doc = "Iterate over the variants of the enum, in the order of the arms of its map:"]
+            #[doc = ""]
+            $( #[doc = ::core::concat!("- `", ::core::stringify!($enum_variant), "`")] )*
+            #[$inline]
+            $vis fn iter(
+            ) -> ::core::array::IntoIter<Self, { $crate::__count_variants!($($enum_variant),*) }> {
+                ::core::iter::IntoIterator::into_iter([$( Self::$enum_variant ),*])
+            }
+        }
+    };
+
+    { true $($rest:tt)* } => {
+        ::core::compile_error!("`iter = true` requires every variant to be a unit variant");
+    };
+
+    { $iter:tt $($rest:tt)* } => {
+        ::core::compile_error!(::core::concat!(
+            "expected `true` or `false` for `iter`, found `", ::core::stringify!($iter), "`",
+        ));
+    };
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::injective_enum_map;
+
+    #[test]
+    fn arm_order() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Channel {
+            Red,
+            Green,
+            Blue,
+        }
+
+        injective_enum_map! {
+            Channel, u8,
+            iter = true,
+            Blue  <=> 2,
+            Red   <=> 0,
+            Green <=> 1,
+        }
+
+        assert_eq!(Channel::iter().len(), 3);
+        assert!(Channel::iter().eq([Channel::Blue, Channel::Red, Channel::Green]));
+        assert!(Channel::iter().map(u8::from).eq([2, 0, 1]));
+    }
+
+    #[test]
+    fn empty() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Empty {}
+
+        injective_enum_map! { Empty, u8, iter = true }
+
+        assert_eq!(Empty::iter().next(), None);
+    }
+}
+
+#[cfg(doctest)]
+pub mod compile_fail_tests {
+    /// ```compile_fail
+    /// use bijective_enum_map::injective_enum_map;
+    /// enum Nonunit {
+    ///     Something(u8),
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     Nonunit, u8,
+    ///     iter = true,
+    ///     Something(n) <=> n,
+    /// }
+    /// ```
+    pub fn _non_unit_variant() {}
+}
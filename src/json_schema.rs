@@ -0,0 +1,197 @@
+#[cfg(feature = "schemars")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_json_schema {
+    {
+        true
+        $enum_ty:ty,
+        $into:ty,
+        $(
+            $enum_variant:ident$(($($tuple:tt)*))?$({$($struct:tt)*})?
+            <=> $value:expr
+        ),*
+        $(,)?
+    } => {
+        impl $crate::__private::schemars::JsonSchema for $enum_ty {
+            fn schema_name() -> $crate::__private::Cow<'static, str> {
+                $crate::__private::Cow::Borrowed(::core::stringify!($enum_ty))
+            }
+
+            fn schema_id() -> $crate::__private::Cow<'static, str> {
+                $crate::__private::Cow::Borrowed(::core::concat!(
+                    ::core::module_path!(), "::", ::core::stringify!($enum_ty),
+                ))
+            }
+
+            fn json_schema(
+                generator: &mut $crate::__private::schemars::SchemaGenerator,
+            ) -> $crate::__private::schemars::Schema {
+                let mut schema = $crate::__private::schemars::json_schema!({
+                    "enum": [$($value),*],
+                });
+                // Only the type is taken from the schema of `$into`, since the permitted values
+                // are listed exhaustively.
+                let into_schema =
+                    <$into as $crate::__private::schemars::JsonSchema>::json_schema(generator);
+                if let ::core::option::Option::Some(ty) = into_schema.get("type") {
+                    schema.insert("type".into(), ty.clone());
+                }
+                schema
+            }
+        }
+    };
+
+    // The schema could only list the canonical value of an arm with a separate reverse pattern.
+    { true $($rest:tt)* } => {
+        ::core::compile_error!(
+            "`json_schema = true` does not support arms with a separate reverse pattern \
+             (`=> pattern`)",
+        );
+    };
+
+    { false $($rest:tt)* } => {};
+
+    { $json_schema:tt $($rest:tt)* } => {
+        ::core::compile_error!(::core::concat!(
+            "expected `true` or `false` for `json_schema`, found `",
+            ::core::stringify!($json_schema), "`",
+        ));
+    };
+}
+
+#[cfg(not(feature = "schemars"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_json_schema {
+    { false $($rest:tt)* } => {};
+
+    { true $($rest:tt)* } => {
+        ::core::compile_error!("the `json_schema` option requires the `schemars` feature");
+    };
+
+    { $json_schema:tt $($rest:tt)* } => {
+        ::core::compile_error!(::core::concat!(
+            "expected `true` or `false` for `json_schema`, found `",
+            ::core::stringify!($json_schema), "`",
+        ));
+    };
+}
+
+
+#[cfg(all(test, feature = "schemars"))]
+mod tests {
+    use schemars::{json_schema, JsonSchema, SchemaGenerator};
+
+    use crate::injective_enum_map;
+
+    #[test]
+    fn string_values() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Color {
+            Red,
+            Green,
+            Blue,
+        }
+
+        injective_enum_map! {
+            Color, &'static str, &str,
+            json_schema = true,
+            Red   <=> "red",
+            Green <=> "green",
+            Blue  <=> "blue",
+        }
+
+        assert_eq!(Color::schema_name(), "Color");
+        assert_eq!(
+            Color::json_schema(&mut SchemaGenerator::default()),
+            json_schema!({
+                "type": "string",
+                "enum": ["red", "green", "blue"],
+            }),
+        );
+    }
+
+    #[test]
+    fn empty() {
+        enum Empty {}
+
+        injective_enum_map! { Empty, &'static str, &str, json_schema = true }
+
+        assert_eq!(
+            Empty::json_schema(&mut SchemaGenerator::default()),
+            json_schema!({
+                "type": "string",
+                "enum": [],
+            }),
+        );
+    }
+
+    #[test]
+    fn integer_values() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Level {
+            Low,
+            High,
+        }
+
+        injective_enum_map! {
+            Level, u8,
+            json_schema = true,
+            Low  <=> 1,
+            High <=> 10,
+        }
+
+        assert_eq!(
+            Level::json_schema(&mut SchemaGenerator::default()),
+            json_schema!({
+                "type": "integer",
+                "enum": [1, 10],
+            }),
+        );
+    }
+
+    #[test]
+    fn with_other_options() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Switch {
+            On,
+            Off,
+        }
+
+        injective_enum_map! {
+            Switch, &'static str, &str,
+            json_schema = true,
+            mode = tables,
+            On  <=> "on",
+            Off <=> "off",
+        }
+
+        assert_eq!(Switch::try_from("on"), Ok(Switch::On));
+        assert_eq!(
+            Switch::json_schema(&mut SchemaGenerator::default()),
+            json_schema!({
+                "type": "string",
+                "enum": ["on", "off"],
+            }),
+        );
+    }
+}
+
+#[cfg(all(doctest, feature = "schemars"))]
+pub mod compile_fail_tests {
+    /// ```compile_fail
+    /// use bijective_enum_map::injective_enum_map;
+    /// enum Level {
+    ///     Low,
+    ///     High,
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     Level, u8,
+    ///     json_schema = true,
+    ///     Low  <=> 0 => 0..=9,
+    ///     High <=> 10,
+    /// }
+    /// ```
+    pub fn _pattern_arm() {}
+}
@@ -3,6 +3,13 @@
 // See https://linebender.org/blog/doc-include for this README inclusion strategy
 //! [`bijective_enum_map`]: bijective_enum_map
 //! [`injective_enum_map`]: injective_enum_map
+//! [`assert_injective`]: assert_injective
+//! [`assert_bijective`]: assert_bijective
+//! [`enum_indexed`]: enum_indexed
+//! [`EnumArray`]: EnumArray
+//! [`NoMatchingVariant`]: NoMatchingVariant
+//! [`ReadError`]: ReadError
+//! [`BufferTooShort`]: BufferTooShort
 //! [`From`]: From
 //! [`TryFrom`]: TryFrom
 // File links are not supported by rustdoc
@@ -14,8 +21,56 @@
 //! </style>
 #![doc =  include_str!("../README.md")]
 
+#[cfg(any(feature = "alloc", feature = "schemars"))]
+extern crate alloc;
+// The derive macros refer to `::bijective_enum_map`, including in this crate's own tests.
+#[cfg(all(test, feature = "derive"))]
+extern crate self as bijective_enum_map;
+
 mod bijective;
+mod bytes;
+mod const_assert;
+mod contiguous;
+mod cow;
+mod error;
+mod expected_values;
+mod indexed;
 mod injective;
-// The helper macros in this module should not be considered part of the public API
+mod iter;
+mod json_schema;
+#[cfg(feature = "derive")]
+mod named;
+mod ordinal;
+mod proptest_impl;
+#[cfg(feature = "derive")]
+mod subset;
+// The helper macros in these modules should not be considered part of the public API
 // (for either usage or semver purposes).
 mod helpers;
+mod options;
+
+
+pub use self::error::{BufferTooShort, NoMatchingVariant, ReadError};
+pub use self::indexed::{EnumArray, EnumIndex};
+#[cfg(feature = "derive")]
+pub use self::named::NamedEnumMap;
+#[cfg(feature = "derive")]
+pub use self::subset::SubsetEnumMap;
+
+// Items used by the macros, which should not be considered part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::const_assert::{
+        arm_index, arm_name, earlier_equal, is_cardinality, is_ordinal_range, last_arm_index,
+        message_len, ordinal_range_error, Key, Message, ValueKey,
+    };
+
+    #[cfg(any(feature = "alloc", feature = "schemars"))]
+    pub use alloc::borrow::Cow;
+    #[cfg(feature = "proptest")]
+    pub use ::proptest;
+    #[cfg(feature = "proptest")]
+    pub use crate::proptest_impl::{fields_strategy, fields_strategy_with};
+    #[cfg(feature = "schemars")]
+    pub use schemars;
+}
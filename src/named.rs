@@ -0,0 +1,115 @@
+/// Derive an injective map between a fieldless enum and the names of its variants, implementing
+/// `From<Enum> for &'static str` and `TryFrom<&str> for Enum` as [`injective_enum_map`] would.
+///
+/// By default, each variant maps to its name exactly as written. The names can be converted with
+/// `#[enum_map(rename_all = "...")]` on the enum, which accepts the same rules as serde:
+/// `"lowercase"`, `"UPPERCASE"`, `"PascalCase"`, `"camelCase"`, `"snake_case"`,
+/// `"SCREAMING_SNAKE_CASE"`, `"kebab-case"`, and `"SCREAMING-KEBAB-CASE"`. Individual variants
+/// can be given a different name with `#[enum_map(rename = "...")]`, which overrides `rename_all`.
+///
+/// Every variant must be a unit variant, the enum must not be generic, and no two variants may
+/// end up with the same name. Requires the `derive` feature.
+///
+/// The `enum_map` attribute is shared with [`SubsetEnumMap`], whose `superset = Enum` and
+/// `rename = Variant` (with an identifier rather than a string) are ignored by this derive.
+///
+/// # Examples
+/// ```
+/// use bijective_enum_map::NamedEnumMap;
+///
+/// #[derive(NamedEnumMap, Debug, PartialEq, Eq)]
+/// #[enum_map(rename_all = "kebab-case")]
+/// enum Setting {
+///     LineWidth,
+///     TabSize,
+///     #[enum_map(rename = "color")]
+///     Colour,
+/// }
+///
+/// assert_eq!(<&str>::from(Setting::LineWidth), "line-width");
+/// assert_eq!(Setting::try_from("tab-size"), Ok(Setting::TabSize));
+/// assert_eq!(Setting::try_from("color"), Ok(Setting::Colour));
+/// assert_eq!(Setting::try_from("colour"), Err(()));
+/// ```
+///
+/// [`injective_enum_map`]: crate::injective_enum_map
+/// [`SubsetEnumMap`]: crate::SubsetEnumMap
+pub use bijective_enum_map_derive::NamedEnumMap;
+
+
+#[cfg(test)]
+mod tests {
+    use super::NamedEnumMap;
+
+    #[test]
+    fn variant_names() {
+        #[derive(NamedEnumMap, Debug, PartialEq, Eq)]
+        enum Unrenamed {
+            First,
+            SecondVariant,
+        }
+
+        assert_eq!(<&str>::from(Unrenamed::SecondVariant), "SecondVariant");
+        assert_eq!(Unrenamed::try_from("First"), Ok(Unrenamed::First));
+        assert_eq!(Unrenamed::try_from("first"), Err(()));
+    }
+
+    #[test]
+    fn rename_all() {
+        #[derive(NamedEnumMap, Debug, PartialEq, Eq)]
+        #[enum_map(rename_all = "SCREAMING_SNAKE_CASE")]
+        enum Level {
+            Info,
+            WarnOnce,
+            #[enum_map(rename = "FATAL")]
+            Error,
+        }
+
+        assert_eq!(<&str>::from(Level::WarnOnce), "WARN_ONCE");
+        assert_eq!(<&str>::from(Level::Error), "FATAL");
+        assert_eq!(Level::try_from("INFO"), Ok(Level::Info));
+        assert_eq!(Level::try_from("ERROR"), Err(()));
+    }
+
+    #[test]
+    fn empty() {
+        #[derive(NamedEnumMap, Debug, PartialEq, Eq)]
+        #[enum_map(rename_all = "camelCase")]
+        enum Empty {}
+
+        assert_eq!(Empty::try_from("empty"), Err(()));
+    }
+}
+
+#[cfg(doctest)]
+pub mod compile_fail_tests {
+    /// ```compile_fail
+    /// use bijective_enum_map::NamedEnumMap;
+    /// #[derive(NamedEnumMap)]
+    /// #[enum_map(rename_all = "lowercase")]
+    /// enum Collision {
+    ///     Abc,
+    ///     ABC,
+    /// }
+    /// ```
+    pub fn _name_collision() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::NamedEnumMap;
+    /// #[derive(NamedEnumMap)]
+    /// #[enum_map(rename_all = "Title Case")]
+    /// enum Unknown {
+    ///     Something,
+    /// }
+    /// ```
+    pub fn _unknown_rule() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::NamedEnumMap;
+    /// #[derive(NamedEnumMap)]
+    /// enum Nonunit {
+    ///     Something(u8),
+    /// }
+    /// ```
+    pub fn _non_unit_variant() {}
+}
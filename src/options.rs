@@ -0,0 +1,562 @@
+/// Parse the options given to `injective_enum_map` or `bijective_enum_map` after the types,
+/// and then implement the map.
+///
+/// Invoked as `@start $kind [$enum_ty, $into, $try_from_or_from] $($body)*` or
+/// `@start $kind [$enum_ty, $both] $($body)*`, where `$kind` is `injective` or `bijective`.
+/// Default options are then placed after the types, and options are removed from the front of
+/// the body (overwriting the defaults) until the arms of the map are reached.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_map_options {
+    {
+        @start $kind:ident $types:tt
+        $($body:tt)*
+    } => {
+        $crate::__enum_map_options! {
+            $kind $types
+            {
+                mode: [match],
+                inline: [default],
+                vis: [pub],
+                error: [default],
+                json_schema: [false],
+                const_assert: [false],
+                iter: [false],
+                ordinal: [false],
+                expected_values: [false],
+                bytes: [false],
+                proptest: [false],
+            }
+            $($body)*
+        }
+    };
+
+    // ================================================================
+    //  Options
+    // ================================================================
+
+    {
+        $kind:ident $types:tt $options:tt
+        vis = $vis:vis $(, $($rest:tt)*)?
+    } => {
+        $crate::__enum_map_options! {
+            @set vis = [$vis] [$kind $types] {} $options
+            $($($rest)*)?
+        }
+    };
+
+    {
+        $kind:ident $types:tt $options:tt
+        vis = $($rest:tt)*
+    } => {
+        ::core::compile_error!(
+            "expected a visibility for `vis`, such as `pub`, `pub(crate)`, or `pub(self)`",
+        );
+    };
+
+    {
+        $kind:ident $types:tt $options:tt
+        error: $error_first:ident $(:: $error_segment:ident)+ $(, $($rest:tt)*)?
+    } => {
+        $crate::__enum_map_options! {
+            @error_path [$error_first] $($error_segment)+;
+            $kind $types $options
+            $($($rest)*)?
+        }
+    };
+
+    {
+        $kind:ident $types:tt $options:tt
+        error: $error:ident $(, $($rest:tt)*)?
+    } => {
+        $crate::__enum_map_options! {
+            @set error = [$error = $error] [$kind $types] {} $options
+            $($($rest)*)?
+        }
+    };
+
+    {
+        $kind:ident $types:tt $options:tt
+        error: $error_ty:ty = $error:expr $(, $($rest:tt)*)?
+    } => {
+        $crate::__enum_map_options! {
+            @set error = [$error_ty = $error] [$kind $types] {} $options
+            $($($rest)*)?
+        }
+    };
+
+    {
+        $kind:ident $types:tt $options:tt
+        error: $($rest:tt)*
+    } => {
+        ::core::compile_error!(
+            "expected `error: Type::Variant`, `error: UnitStruct`, or `error: Type = expression`",
+        );
+    };
+
+    {
+        $kind:ident $types:tt $options:tt
+        $option:ident = $value:tt $(, $($rest:tt)*)?
+    } => {
+        $crate::__enum_map_options! {
+            @set $option = [$value] [$kind $types] {} $options
+            $($($rest)*)?
+        }
+    };
+
+    // Split `error: Type::Variant` into the type and the error, by moving all but the last path
+    // segment into the type.
+
+    {
+        @error_path [$($error_ty:ident)::+] $error_variant:ident;
+        $kind:ident $types:tt $options:tt
+        $($rest:tt)*
+    } => {
+        $crate::__enum_map_options! {
+            @set error = [$($error_ty)::+ = $($error_ty::)+ $error_variant] [$kind $types] {}
+            $options
+            $($rest)*
+        }
+    };
+
+    {
+        @error_path [$($error_ty:ident)::+] $error_segment:ident $($more:ident)+;
+        $($rest:tt)*
+    } => {
+        $crate::__enum_map_options! {
+            @error_path [$($error_ty)::+ :: $error_segment] $($more)+;
+            $($rest)*
+        }
+    };
+
+    // Replace the value of an option, searching through the options with a tt-muncher.
+
+    {
+        @set mode = $value:tt [$kind:ident $types:tt]
+        {$($done:tt)*} {mode: $_old:tt, $($rest:tt)*}
+        $($body:tt)*
+    } => {
+        $crate::__enum_map_options! {
+            $kind $types {$($done)* mode: $value, $($rest)*} $($body)*
+        }
+    };
+
+    {
+        @set inline = $value:tt [$kind:ident $types:tt]
+        {$($done:tt)*} {inline: $_old:tt, $($rest:tt)*}
+        $($body:tt)*
+    } => {
+        $crate::__enum_map_options! {
+            $kind $types {$($done)* inline: $value, $($rest)*} $($body)*
+        }
+    };
+
+    {
+        @set vis = $value:tt [$kind:ident $types:tt]
+        {$($done:tt)*} {vis: $_old:tt, $($rest:tt)*}
+        $($body:tt)*
+    } => {
+        $crate::__enum_map_options! {
+            $kind $types {$($done)* vis: $value, $($rest)*} $($body)*
+        }
+    };
+
+    {
+        @set error = $value:tt [$kind:ident $types:tt]
+        {$($done:tt)*} {error: $_old:tt, $($rest:tt)*}
+        $($body:tt)*
+    } => {
+        $crate::__enum_map_options! {
+            $kind $types {$($done)* error: $value, $($rest)*} $($body)*
+        }
+    };
+
+    {
+        @set json_schema = $value:tt [$kind:ident $types:tt]
+        {$($done:tt)*} {json_schema: $_old:tt, $($rest:tt)*}
+        $($body:tt)*
+    } => {
+        $crate::__enum_map_options! {
+            $kind $types {$($done)* json_schema: $value, $($rest)*} $($body)*
+        }
+    };
+
+    {
+        @set const_assert = $value:tt [$kind:ident $types:tt]
+        {$($done:tt)*} {const_assert: $_old:tt, $($rest:tt)*}
+        $($body:tt)*
+    } => {
+        $crate::__enum_map_options! {
+            $kind $types {$($done)* const_assert: $value, $($rest)*} $($body)*
+        }
+    };
+
+    {
+        @set iter = $value:tt [$kind:ident $types:tt]
+        {$($done:tt)*} {iter: $_old:tt, $($rest:tt)*}
+        $($body:tt)*
+    } => {
+        $crate::__enum_map_options! {
+            $kind $types {$($done)* iter: $value, $($rest)*} $($body)*
+        }
+    };
+
+    {
+        @set ordinal = $value:tt [$kind:ident $types:tt]
+        {$($done:tt)*} {ordinal: $_old:tt, $($rest:tt)*}
+        $($body:tt)*
+    } => {
+        $crate::__enum_map_options! {
+            $kind $types {$($done)* ordinal: $value, $($rest)*} $($body)*
+        }
+    };
+
+    {
+        @set expected_values = $value:tt [$kind:ident $types:tt]
+        {$($done:tt)*} {expected_values: $_old:tt, $($rest:tt)*}
+        $($body:tt)*
+    } => {
+        $crate::__enum_map_options! {
+            $kind $types {$($done)* expected_values: $value, $($rest)*} $($body)*
+        }
+    };
+
+    {
+        @set bytes = $value:tt [$kind:ident $types:tt]
+        {$($done:tt)*} {bytes: $_old:tt, $($rest:tt)*}
+        $($body:tt)*
+    } => {
+        $crate::__enum_map_options! {
+            $kind $types {$($done)* bytes: $value, $($rest)*} $($body)*
+        }
+    };
+
+    {
+        @set proptest = $value:tt [$kind:ident $types:tt]
+        {$($done:tt)*} {proptest: $_old:tt, $($rest:tt)*}
+        $($body:tt)*
+    } => {
+        $crate::__enum_map_options! {
+            $kind $types {$($done)* proptest: $value, $($rest)*} $($body)*
+        }
+    };
+
+    {
+        @set $option:ident = $value:tt $head:tt
+        {$($done:tt)*} {$name:ident: $old:tt, $($rest:tt)*}
+        $($body:tt)*
+    } => {
+        $crate::__enum_map_options! {
+            @set $option = $value $head {$($done)* $name: $old,} {$($rest)*}
+            $($body)*
+        }
+    };
+
+    { @set $option:ident = $value:tt $head:tt $done:tt {} $($body:tt)* } => {
+        ::core::compile_error!(::core::concat!(
+            "unknown option `", ::core::stringify!($option), "`",
+        ));
+    };
+
+    // ================================================================
+    //  Implementations
+    // ================================================================
+
+    {
+        $kind:ident [$enum_ty:ty, $both:ty] $options:tt
+        $($body:tt)*
+    } => {
+        $crate::__enum_map_options! {
+            @finish $kind [$enum_ty, $both, $both] $options
+            $($body)*
+        }
+    };
+
+    {
+        $kind:ident [$enum_ty:ty, $into:ty, $try_from_or_from:ty]
+        { mode: [cow], $($options:tt)* }
+        $($body:tt)*
+    } => {
+        ::core::compile_error!(
+            "`mode = cow` takes only two types: the enum and `Cow<'static, str>`",
+        );
+    };
+
+    {
+        $kind:ident [$enum_ty:ty, $into:ty, $try_from_or_from:ty] $options:tt
+        $($body:tt)*
+    } => {
+        $crate::__enum_map_options! {
+            @finish $kind [$enum_ty, $into, $try_from_or_from] $options
+            $($body)*
+        }
+    };
+
+    {
+        @finish $kind:ident [$enum_ty:ty, $into:ty, $try_from_or_from:ty]
+        {
+            mode: [$mode:tt],
+            inline: [$inline:tt],
+            vis: [$vis:vis],
+            error: $error:tt,
+            json_schema: [$json_schema:tt],
+            const_assert: [$const_assert:tt],
+            iter: [$iter:tt],
+            ordinal: [$ordinal:tt],
+            expected_values: [$expected_values:tt],
+            bytes: [$bytes:tt],
+            proptest: [$proptest:tt],
+        }
+        $($body:tt)*
+    } => {
+        $crate::__enum_map_options! {
+            @inline $inline
+            $kind [$enum_ty, $into, $try_from_or_from]
+            {
+                mode: [$mode],
+                vis: [$vis],
+                error: $error,
+                json_schema: [$json_schema],
+                const_assert: [$const_assert],
+                iter: [$iter],
+                ordinal: [$ordinal],
+                expected_values: [$expected_values],
+                bytes: [$bytes],
+                proptest: [$proptest],
+            }
+            $($body)*
+        }
+    };
+
+    { @inline default $($rest:tt)* } => {
+        $crate::__enum_map_options! { @generate [inline] $($rest)* }
+    };
+
+    { @inline never $($rest:tt)* } => {
+        $crate::__enum_map_options! { @generate [inline(never)] $($rest)* }
+    };
+
+    { @inline always $($rest:tt)* } => {
+        $crate::__enum_map_options! { @generate [inline(always)] $($rest)* }
+    };
+
+    { @inline $inline:tt $($rest:tt)* } => {
+        ::core::compile_error!(::core::concat!(
+            "expected `default`, `never`, or `always` for `inline`, found `",
+            ::core::stringify!($inline), "`",
+        ));
+    };
+
+    {
+        @generate [$inline:meta] $kind:ident [$enum_ty:ty, $into:ty, $try_from_or_from:ty]
+        {
+            mode: [$mode:tt],
+            vis: [$vis:vis],
+            error: $error:tt,
+            json_schema: [$json_schema:tt],
+            const_assert: [$const_assert:tt],
+            iter: [$iter:tt],
+            ordinal: [$ordinal:tt],
+            expected_values: [$expected_values:tt],
+            bytes: [$bytes:tt],
+            proptest: [$proptest:tt],
+        }
+        $($body:tt)*
+    } => {
+        $crate::__enum_map_options! {
+            @impl [$inline] $error $kind $mode [$enum_ty, $into, $try_from_or_from]
+            $($body)*
+        }
+        $crate::__impl_json_schema! { $json_schema $enum_ty, $into, $($body)* }
+        $crate::__const_assert! { $const_assert $kind $enum_ty, $into, $($body)* }
+        $crate::__impl_iter! { $iter [$vis] [$inline] $enum_ty, $($body)* }
+        $crate::__impl_ordinal! { $ordinal [$vis] [$inline] $enum_ty, $into, $($body)* }
+        $crate::__impl_expected_values! {
+            $expected_values [$vis] [$inline] $mode $enum_ty, $into, $($body)*
+        }
+        $crate::__impl_bytes! { $bytes [$vis] [$inline] $enum_ty, $into, $try_from_or_from }
+        $crate::__impl_proptest! { $proptest [$vis] [$inline] $enum_ty, $($body)* }
+    };
+
+    { @impl $inline:tt [default] injective $($rest:tt)* } => {
+        $crate::__enum_map_options! { @impl $inline [() = ()] injective $($rest)* }
+    };
+
+    { @impl $inline:tt [$error_ty:ty = $error:expr] bijective $($rest:tt)* } => {
+        ::core::compile_error!(
+            "the `error` option is only supported by `injective_enum_map`, since the conversions \
+             of `bijective_enum_map` cannot fail",
+        );
+    };
+
+    {
+        @impl [$inline:meta] [$error_ty:ty = $error:expr]
+        injective match [$enum_ty:ty, $into:ty, $try_from:ty]
+        $($body:tt)*
+    } => {
+        $crate::__impl_from_enum! { [$inline] $enum_ty, $into, $($body)* }
+        $crate::__impl_enum_try_from! {
+            [$inline] [$error_ty = $error] $enum_ty, $try_from, $($body)*
+        }
+    };
+
+    {
+        @impl [$inline:meta] [default] bijective match [$enum_ty:ty, $into:ty, $from:ty]
+        $($body:tt)*
+    } => {
+        $crate::__impl_from_enum! { [$inline] $enum_ty, $into, $($body)* }
+        $crate::__impl_enum_from! { [$inline] $enum_ty, $from, $($body)* }
+    };
+
+    {
+        @impl $inline:tt $error:tt $kind:ident tables $types:tt
+        $($body:tt)*
+    } => {
+        $crate::__enum_map_options! {
+            @tables [$inline $error $kind $types] [$($body)*] $($body)*
+        }
+    };
+
+    // Check that the arms are supported by `mode = tables`, and pass along the unparsed arms.
+
+    {
+        @tables [$($impl:tt)*] $body:tt
+        $($enum_variant:ident <=> $value:expr),* $(,)?
+    } => {
+        $crate::__enum_map_options! { @tables_impl $($impl)* $body }
+    };
+
+    { @tables $impl:tt $body:tt $($rest:tt)* } => {
+        ::core::compile_error!(
+            "`mode = tables` requires every arm to map a unit variant to a value, without a \
+             separate reverse pattern (`=> pattern`)",
+        );
+    };
+
+    {
+        @tables_impl [$inline:meta] [$error_ty:ty = $error:expr]
+        injective [$enum_ty:ty, $into:ty, $try_from:ty]
+        [$($body:tt)*]
+    } => {
+        $crate::__impl_tables_from_enum! { [$inline] $enum_ty, $into, $($body)* }
+        $crate::__impl_tables_enum_try_from! {
+            [$inline] [$error_ty = $error] $enum_ty, $try_from, $($body)*
+        }
+        $crate::__check_patterns! { nonexhaustive $try_from, $($body)* }
+    };
+
+    {
+        @tables_impl [$inline:meta] [default] bijective [$enum_ty:ty, $into:ty, $from:ty]
+        [$($body:tt)*]
+    } => {
+        $crate::__impl_tables_from_enum! { [$inline] $enum_ty, $into, $($body)* }
+        $crate::__impl_tables_enum_from! { [$inline] $enum_ty, $from, $($body)* }
+        $crate::__check_patterns! { exhaustive $from, $($body)* }
+    };
+
+    {
+        @impl [$inline:meta] [$error_ty:ty = $error:expr]
+        injective cow [$enum_ty:ty, $into:ty, $try_from:ty]
+        $($body:tt)*
+    } => {
+        $crate::__impl_cow! { [$inline] [$error_ty = $error] $enum_ty, $into, $($body)* }
+    };
+
+    {
+        @impl $inline:tt $error:tt bijective cow $types:tt
+        $($body:tt)*
+    } => {
+        ::core::compile_error!("`mode = cow` is only supported by `injective_enum_map`");
+    };
+
+    {
+        @impl [$inline:meta] $error:tt $kind:ident contiguous [$enum_ty:ty, $into:ty, $other:ty]
+        $($body:tt)*
+    } => {
+        $crate::__impl_contiguous! {
+            [$inline] $error $kind $enum_ty, $into, $other, $($body)*
+        }
+    };
+
+    {
+        @impl $inline:tt $error:tt $kind:ident $mode:tt $types:tt
+        $($body:tt)*
+    } => {
+        ::core::compile_error!(::core::concat!(
+            "unknown mode `", ::core::stringify!($mode),
+            "`; expected `match`, `tables`, `contiguous`, or `cow`",
+        ));
+    };
+}
+
+
+#[cfg(test)]
+mod tests {
+    // Every option which generates inherent functions is enabled for one enum, in a separate
+    // module so that `vis` applies to the functions used from outside it.
+    mod visibility {
+        use crate::injective_enum_map;
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum Level {
+            Low,
+            High,
+        }
+
+        injective_enum_map! {
+            Level, u8,
+            mode = tables,
+            vis = pub(super),
+            iter = true,
+            ordinal = true,
+            expected_values = true,
+            bytes = le,
+            Low  <=> 0,
+            High <=> 1,
+        }
+    }
+
+    #[test]
+    fn restricted_visibility() {
+        use self::visibility::Level;
+
+        assert!(Level::iter().eq([Level::Low, Level::High]));
+
+        assert_eq!(Level::Low.next(), Some(Level::High));
+        assert_eq!(Level::High.prev(), Some(Level::Low));
+        assert_eq!(Level::High.wrapping_next(), Level::Low);
+        assert_eq!(Level::Low.checked_add(1), Some(Level::High));
+
+        assert_eq!(Level::expected_values(), [0, 1]);
+
+        let mut buf = [0_u8; 1];
+        assert_eq!(Level::High.write_to(&mut buf), Ok(&mut [][..]));
+        assert_eq!(Level::try_read_from(&buf), Ok((Level::High, &[][..])));
+    }
+}
+
+#[cfg(doctest)]
+pub mod compile_fail_tests {
+    /// ```compile_fail,E0624
+    /// mod inner {
+    ///     use bijective_enum_map::injective_enum_map;
+    ///
+    ///     #[derive(Clone, Copy)]
+    ///     pub enum Private {
+    ///         Something,
+    ///     }
+    ///
+    ///     injective_enum_map! {
+    ///         Private, u8,
+    ///         vis = pub(self),
+    ///         iter = true,
+    ///         ordinal = true,
+    ///         expected_values = true,
+    ///         Something <=> 0,
+    ///     }
+    /// }
+    ///
+    /// let _ = inner::Private::iter();
+    /// ```
+    pub fn _private_items() {}
+}
@@ -0,0 +1,418 @@
+#[cfg(feature = "proptest")]
+use core::fmt::Debug;
+
+#[cfg(feature = "proptest")]
+use ::proptest::{arbitrary::{any, Arbitrary}, strategy::{BoxedStrategy, Strategy}};
+
+
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_proptest {
+    { false $($rest:tt)* } => {};
+
+    {
+        true [$vis:vis] [$inline:meta]
+        $enum_ty:ty,
+        $($enum_variant:ident <=> $value:expr $(=> $pattern:pat)?),+
+        $(,)?
+    } => {
+        impl $crate::__private::proptest::arbitrary::Arbitrary for $enum_ty {
+            type Parameters = ();
+            type Strategy = $crate::__private::proptest::strategy::Union<
+                $crate::__private::proptest::strategy::LazyJust<Self, fn() -> Self>,
+            >;
+
+            fn arbitrary_with(_args: ()) -> Self::Strategy {
+                $crate::__private::proptest::strategy::Union::new([
+                    $(
+                        $crate::__private::proptest::strategy::LazyJust::new(
+                            (|| Self::$enum_variant) as fn() -> Self,
+                        )
+                    ),+
+                ])
+            }
+        }
+
+        impl $enum_ty {
+            #[doc = "A `proptest` strategy generating each variant of the enum in its map:"]
+            #[doc = ""]
+            $( #[doc = ::core::concat!("- `", ::core::stringify!($enum_variant), "`")] )+
+            #[$inline]
+            $vis fn any_variant(
+            ) -> impl $crate::__private::proptest::strategy::Strategy<Value = Self> {
+                <Self as $crate::__private::proptest::arbitrary::Arbitrary>::arbitrary()
+            }
+        }
+    };
+
+    { true [$vis:vis] [$inline:meta] $enum_ty:ty $(, $($body:tt)*)? } => {
+        $crate::__impl_proptest! { @any {} [$vis] [$inline] $enum_ty $(, $($body)*)? }
+    };
+
+    {
+        {$($strategies:tt)*} [$vis:vis] [$inline:meta] $enum_ty:ty $(, $($body:tt)*)?
+    } => {
+        $crate::__impl_proptest! {
+            @any {$($strategies)*} [$vis] [$inline] $enum_ty $(, $($body)*)?
+        }
+    };
+
+    // The `$` token is passed along so that the helper macros declared in `any_variant` can
+    // have catch-all rules of their own.
+    { @any $strategies:tt $($rest:tt)* } => {
+        $crate::__impl_proptest! { @dollar ($) $strategies $($rest)* }
+    };
+
+    {
+        @dollar ($d:tt)
+        {$($strategy_variant:ident($($strategy:expr),* $(,)?)),* $(,)?}
+        [$vis:vis] [$inline:meta]
+        $enum_ty:ty,
+        $(
+            $enum_variant:ident$(($($tuple:tt)*))?$({$($struct:tt)*})?
+            <=> $value:expr $(=> $pattern:pat)?
+        ),+
+        $(,)?
+    } => {
+        impl $enum_ty {
+            #[doc = "A `proptest` strategy generating each variant of the enum in its map, with "]
+            #[doc = "generated fields:"]
+            #[doc = ""]
+            $(
+                #[doc = ::core::concat!(
+                    "- `", ::core::stringify!($enum_variant),
+                    $( "(", ::core::stringify!($($tuple)*), ")", )?
+                    $( " { ", ::core::stringify!($($struct)*), " }", )?
+                    "`",
+                )]
+            )+
+            #[$inline]
+            $vis fn any_variant(
+            ) -> impl $crate::__private::proptest::strategy::Strategy<Value = Self> {
+                #[allow(unused_macros)]
+                macro_rules! __is_variant {
+                    $( ($enum_variant) => {}; )+
+                    ($d other:ident) => {
+                        ::core::compile_error!(::core::concat!(
+                            "a `proptest` strategy was given for `", ::core::stringify!($d other),
+                            "`, which is not a variant in the map",
+                        ));
+                    };
+                }
+
+                macro_rules! __variant_strategy {
+                    $(
+                        (@unit $strategy_variant) => {
+                            ::core::compile_error!(::core::concat!(
+                                "a `proptest` strategy was given for `",
+                                ::core::stringify!($strategy_variant),
+                                "`, which is a unit variant",
+                            ))
+                        };
+                        ($strategy_variant, $d constructor:expr) => {
+                            $crate::__private::fields_strategy_with(
+                                ($($strategy,)*),
+                                $d constructor,
+                            )
+                        };
+                    )*
+                    (@unit $d enum_variant:ident) => {
+                        $crate::__private::proptest::strategy::Strategy::boxed(
+                            $crate::__private::proptest::strategy::LazyJust::new(
+                                || Self::$d enum_variant,
+                            ),
+                        )
+                    };
+                    ($d enum_variant:ident, $d constructor:expr) => {
+                        $crate::__private::fields_strategy($d constructor)
+                    };
+                }
+
+                $( __is_variant!($strategy_variant); )*
+
+                $crate::__private::proptest::strategy::Union::new([
+                    $(
+                        $crate::__impl_proptest! {
+                            @variant __variant_strategy
+                            $enum_variant$(($($tuple)*))?$({$($struct)*})?
+                        }
+                    ),+
+                ])
+            }
+        }
+    };
+
+    { @dollar $d:tt $strategies:tt [$vis:vis] [$inline:meta] $enum_ty:ty $(,)? } => {
+        ::core::compile_error!("`proptest` requires at least one variant");
+    };
+
+    {
+        @dollar $d:tt $strategies:tt $($rest:tt)*
+    } => {
+        ::core::compile_error!(
+            "expected `{ Variant(strategy, ...), ... }` for `proptest`, with one strategy for \
+             each field of the variant",
+        );
+    };
+
+    { @variant $lookup:ident $enum_variant:ident } => {
+        $lookup!(@unit $enum_variant)
+    };
+
+    { @variant $lookup:ident $enum_variant:ident($($field:ident),* $(,)?) } => {
+        $lookup!($enum_variant, |($($field,)*)| Self::$enum_variant($($field),*))
+    };
+
+    { @variant $lookup:ident $enum_variant:ident { $($field:ident),* $(,)? } } => {
+        $lookup!($enum_variant, |($($field,)*)| Self::$enum_variant { $($field),* })
+    };
+
+    { @variant $($rest:tt)* } => {
+        ::core::compile_error!(
+            "`proptest` requires the fields of each variant to be bound to identifiers, \
+             such as `Variant(a, b)` or `Variant { a, b }`",
+        )
+    };
+
+    { $proptest:tt $($rest:tt)* } => {
+        ::core::compile_error!(::core::concat!(
+            "expected `true`, `false`, or `{ ... }` for `proptest`, found `",
+            ::core::stringify!($proptest), "`",
+        ));
+    };
+}
+
+#[cfg(not(feature = "proptest"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_proptest {
+    { false $($rest:tt)* } => {};
+
+    { true $($rest:tt)* } => {
+        ::core::compile_error!("the `proptest` option requires the `proptest` feature");
+    };
+
+    { {$($strategies:tt)*} $($rest:tt)* } => {
+        ::core::compile_error!("the `proptest` option requires the `proptest` feature");
+    };
+
+    { $proptest:tt $($rest:tt)* } => {
+        ::core::compile_error!(::core::concat!(
+            "expected `true`, `false`, or `{ ... }` for `proptest`, found `",
+            ::core::stringify!($proptest), "`",
+        ));
+    };
+}
+
+/// A strategy for a variant with fields, which generates a tuple of arbitrary fields and passes
+/// it to `constructor`. The types of the fields are inferred from `constructor`.
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+pub fn fields_strategy<A, T>(constructor: fn(A) -> T) -> BoxedStrategy<T>
+where
+    A: Arbitrary + 'static,
+    A::Strategy: 'static,
+    T: Debug + 'static,
+{
+    any::<A>().prop_map(constructor).boxed()
+}
+
+
+/// A strategy for a variant with fields, which generates a tuple of fields with `strategy` and
+/// passes it to `constructor`.
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+pub fn fields_strategy_with<S, T>(strategy: S, constructor: fn(S::Value) -> T) -> BoxedStrategy<T>
+where
+    S: Strategy + 'static,
+    T: Debug + 'static,
+{
+    strategy.prop_map(constructor).boxed()
+}
+
+#[cfg(all(test, feature = "proptest"))]
+mod tests {
+    use ::proptest::{
+        arbitrary::any,
+        strategy::{Just, Strategy, ValueTree},
+        test_runner::TestRunner,
+    };
+
+    use crate::{bijective_enum_map, injective_enum_map};
+
+    /// Generate values from a strategy with a deterministic RNG.
+    fn generate<S: Strategy>(strategy: &S, count: usize) -> impl Iterator<Item = S::Value> + '_ {
+        let mut runner = TestRunner::deterministic();
+        (0..count).map(move |_| strategy.new_tree(&mut runner).unwrap().current())
+    }
+
+    #[test]
+    fn unit_variants() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Direction {
+            North,
+            East,
+            South,
+            West,
+        }
+
+        injective_enum_map! {
+            Direction, &'static str, &str,
+            proptest = true,
+            North <=> "north",
+            East  <=> "east",
+            South <=> "south",
+            West  <=> "west",
+        }
+
+        let mut seen = [false; 4];
+        for direction in generate(&any::<Direction>(), 256) {
+            seen[direction as usize] = true;
+        }
+        assert_eq!(seen, [true; 4]);
+
+        assert_eq!(generate(&Direction::any_variant(), 64).count(), 64);
+    }
+
+    #[test]
+    fn fields() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Shape {
+            Empty,
+            Circle(u8),
+            Rectangle { width: u8, height: u8 },
+        }
+
+        injective_enum_map! {
+            Shape, (u8, u8, u8),
+            proptest = true,
+            vis = pub(crate),
+            Empty                       <=> (0, 0, 0),
+            Circle(radius)              <=> (1, radius, 0),
+            Rectangle { width, height } <=> (2, width, height),
+        }
+
+        let mut seen = [false; 3];
+        for shape in generate(&Shape::any_variant(), 256) {
+            let (index, _, _) = <(u8, u8, u8)>::from(shape);
+            seen[usize::from(index)] = true;
+        }
+        assert_eq!(seen, [true; 3]);
+    }
+
+    #[test]
+    fn strategies() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Shape {
+            Empty,
+            Circle(u8),
+            Rectangle { width: u8, height: u8 },
+        }
+
+        injective_enum_map! {
+            Shape, (u8, u8, u8),
+            proptest = {
+                Circle(1..=10_u8),
+                Rectangle(Just(3_u8), any::<u8>()),
+            },
+            Empty                       <=> (0, 0, 0),
+            Circle(radius)              <=> (1, radius, 0),
+            Rectangle { width, height } <=> (2, width, height),
+        }
+
+        let mut seen = [false; 3];
+        for shape in generate(&Shape::any_variant(), 256) {
+            match shape {
+                Shape::Empty => seen[0] = true,
+                Shape::Circle(radius) => {
+                    assert!((1..=10).contains(&radius));
+                    seen[1] = true;
+                }
+                Shape::Rectangle { width, .. } => {
+                    assert_eq!(width, 3);
+                    seen[2] = true;
+                }
+            }
+        }
+        assert_eq!(seen, [true; 3]);
+    }
+
+    #[test]
+    fn bijective() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Switch {
+            On,
+            Off,
+        }
+
+        bijective_enum_map! {
+            Switch, bool,
+            proptest = true,
+            On  <=> true,
+            Off <=> false,
+        }
+
+        let ons = generate(&Switch::any_variant(), 64).map(bool::from).filter(|on| *on).count();
+        assert!(0 < ons && ons < 64);
+    }
+}
+
+#[cfg(all(doctest, feature = "proptest"))]
+pub mod compile_fail_tests {
+    /// ```compile_fail
+    /// use bijective_enum_map::injective_enum_map;
+    /// #[derive(Debug)]
+    /// enum Number {
+    ///     Zero(u8),
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     Number, u8,
+    ///     proptest = true,
+    ///     Zero(0) <=> 0,
+    /// }
+    /// ```
+    pub fn _non_binding_field() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::injective_enum_map;
+    /// #[derive(Debug)]
+    /// enum Empty {}
+    ///
+    /// injective_enum_map! { Empty, u8, proptest = true }
+    /// ```
+    pub fn _empty() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::injective_enum_map;
+    /// #[derive(Debug)]
+    /// enum Number {
+    ///     Small(u8),
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     Number, u8,
+    ///     proptest = { Large(0..10_u8) },
+    ///     Small(value) <=> value,
+    /// }
+    /// ```
+    pub fn _unknown_strategy_variant() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::injective_enum_map;
+    /// #[derive(Debug)]
+    /// enum Number {
+    ///     Zero,
+    ///     Small(u8),
+    /// }
+    ///
+    /// injective_enum_map! {
+    ///     Number, u8,
+    ///     proptest = { Zero(0..10_u8) },
+    ///     Zero         <=> 0 => 0,
+    ///     Small(value) <=> value,
+    /// }
+    /// ```
+    pub fn _unit_strategy_variant() {}
+}
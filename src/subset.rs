@@ -0,0 +1,202 @@
+/// Derive an injective map from an enum into a "superset" enum, pairing up variants with the same
+/// name, and implementing `From<Enum> for Superset` and `TryFrom<Superset> for Enum` as
+/// [`injective_enum_map`] would.
+///
+/// The superset enum is named with `#[enum_map(superset = Superset)]` on the enum (any path to it
+/// may be used). By default, each variant maps to the variant of the superset with the same name;
+/// individual variants can instead be mapped to a differently named variant with
+/// `#[enum_map(rename = OtherName)]`. Variants with fields are supported, in which case the
+/// corresponding variant of the superset must have the same fields (with the same types), which
+/// are moved across unchanged. Any other variants of the superset make the `TryFrom` conversion
+/// fail with `Err(())`.
+///
+/// The enum must not be generic, and no two variants may map to the same variant of the superset.
+/// Requires the `derive` feature.
+///
+/// The `enum_map` attribute is shared with [`NamedEnumMap`], so both derives may be used on the
+/// same enum: `rename` with an identifier picks the variant of the superset, while `rename` with
+/// a string (and `rename_all` on the enum) picks the name used by [`NamedEnumMap`].
+///
+/// # Examples
+/// ```
+/// use bijective_enum_map::SubsetEnumMap;
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// enum Internal {
+///     Idle,
+///     Busy { jobs: u32 },
+///     Failed(&'static str),
+///     Recovering,
+/// }
+///
+/// #[derive(SubsetEnumMap, Debug, PartialEq, Eq)]
+/// #[enum_map(superset = Internal)]
+/// enum Status {
+///     Idle,
+///     Busy { jobs: u32 },
+///     #[enum_map(rename = Failed)]
+///     Error(&'static str),
+/// }
+///
+/// assert_eq!(Internal::from(Status::Busy { jobs: 2 }), Internal::Busy { jobs: 2 });
+/// assert_eq!(Internal::from(Status::Error("disk full")), Internal::Failed("disk full"));
+/// assert_eq!(Status::try_from(Internal::Idle), Ok(Status::Idle));
+/// assert_eq!(Status::try_from(Internal::Recovering), Err(()));
+/// ```
+///
+/// [`injective_enum_map`]: crate::injective_enum_map
+/// [`NamedEnumMap`]: crate::NamedEnumMap
+pub use bijective_enum_map_derive::SubsetEnumMap;
+
+
+#[cfg(test)]
+mod tests {
+    use super::SubsetEnumMap;
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Internal {
+        Start,
+        Stop,
+        Move(i32, i32),
+        Resize { width: u32, height: u32 },
+        Debug,
+    }
+
+    #[test]
+    fn same_names() {
+        #[derive(SubsetEnumMap, Debug, PartialEq, Eq)]
+        #[enum_map(superset = Internal)]
+        enum Command {
+            Start,
+            Stop,
+            Move(i32, i32),
+            Resize { width: u32, height: u32 },
+        }
+
+        assert_eq!(Internal::from(Command::Stop), Internal::Stop);
+        assert_eq!(Internal::from(Command::Move(-1, 2)), Internal::Move(-1, 2));
+        assert_eq!(
+            Command::try_from(Internal::Resize { width: 3, height: 4 }),
+            Ok(Command::Resize { width: 3, height: 4 }),
+        );
+        assert_eq!(Command::try_from(Internal::Debug), Err(()));
+    }
+
+    #[test]
+    fn renamed_variants() {
+        #[derive(SubsetEnumMap, Debug, PartialEq, Eq)]
+        #[enum_map(superset = self::Internal)]
+        enum Command {
+            #[enum_map(rename = Start)]
+            Begin,
+            #[enum_map(rename = Stop)]
+            End,
+            #[enum_map(rename = Move)]
+            Translate(i32, i32),
+        }
+
+        assert_eq!(Internal::from(Command::Begin), Internal::Start);
+        assert_eq!(Internal::from(Command::Translate(5, 6)), Internal::Move(5, 6));
+        assert_eq!(Command::try_from(Internal::Stop), Ok(Command::End));
+        assert_eq!(Command::try_from(Internal::Resize { width: 0, height: 0 }), Err(()));
+    }
+
+    #[test]
+    fn with_named_enum_map() {
+        use crate::NamedEnumMap;
+
+        #[derive(SubsetEnumMap, NamedEnumMap, Debug, PartialEq, Eq)]
+        #[enum_map(superset = Internal, rename_all = "lowercase")]
+        enum Command {
+            Start,
+            #[enum_map(rename = Stop, rename = "halt")]
+            Halt,
+        }
+
+        assert_eq!(Internal::from(Command::Halt), Internal::Stop);
+        assert_eq!(<&str>::from(Command::Halt), "halt");
+        assert_eq!(Command::try_from("start"), Ok(Command::Start));
+        assert_eq!(Command::try_from(Internal::Debug), Err(()));
+    }
+
+    #[test]
+    fn empty() {
+        #[derive(SubsetEnumMap, Debug, PartialEq, Eq)]
+        #[enum_map(superset = Internal)]
+        enum Empty {}
+
+        assert_eq!(Empty::try_from(Internal::Start), Err(()));
+    }
+}
+
+#[cfg(doctest)]
+pub mod compile_fail_tests {
+    /// ```compile_fail
+    /// use bijective_enum_map::SubsetEnumMap;
+    /// enum Internal {
+    ///     Start,
+    ///     Stop,
+    /// }
+    ///
+    /// #[derive(SubsetEnumMap)]
+    /// #[enum_map(superset = Internal)]
+    /// enum Command {
+    ///     Start,
+    ///     #[enum_map(rename = Start)]
+    ///     Begin,
+    /// }
+    /// ```
+    pub fn _same_target() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::SubsetEnumMap;
+    /// enum Internal {
+    ///     Start,
+    /// }
+    ///
+    /// #[derive(SubsetEnumMap)]
+    /// #[enum_map(superset = Internal)]
+    /// enum Command {
+    ///     Start,
+    ///     Restart,
+    /// }
+    /// ```
+    pub fn _missing_variant() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::SubsetEnumMap;
+    /// #[derive(SubsetEnumMap)]
+    /// enum Command {
+    ///     Start,
+    /// }
+    /// ```
+    pub fn _missing_superset() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::SubsetEnumMap;
+    /// enum Internal {
+    ///     Move(i32, i32),
+    /// }
+    ///
+    /// #[derive(SubsetEnumMap)]
+    /// #[enum_map(superset = Internal)]
+    /// enum Command {
+    ///     Move(u32, u32),
+    /// }
+    /// ```
+    pub fn _mismatched_fields() {}
+
+    /// ```compile_fail
+    /// use bijective_enum_map::SubsetEnumMap;
+    /// enum Internal {
+    ///     Start,
+    /// }
+    ///
+    /// #[derive(SubsetEnumMap)]
+    /// #[enum_map(superset = Internal, subset = Internal)]
+    /// enum Command {
+    ///     Start,
+    /// }
+    /// ```
+    pub fn _unknown_attribute() {}
+}